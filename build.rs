@@ -0,0 +1,4 @@
+// Days are discovered by scanning 'src' in 'aoc_registry!', so adding a day directory has to trigger a rebuild
+fn main() {
+    println!("cargo:rerun-if-changed=src");
+}
@@ -0,0 +1,251 @@
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use std::fs;
+use std::path::{Path, PathBuf};
+use syn::meta::ParseNestedMeta;
+use syn::{Expr, ExprLit, Ident, Item, ItemStruct, Lit, LitStr};
+
+// Arguments of '#[aoc(day = 5, title = "Supply Stacks", tags = ["parsing"], answers = ("CMZ", "MCD"))]'
+#[derive(Default)]
+struct Aoc {
+    day: Option<u8>,
+    title: Option<LitStr>,
+    tags: Vec<LitStr>,
+    answers: Option<(LitStr, LitStr)>,
+}
+
+// Numbers and strings are both accepted as answers, they end up as text in the metadata
+fn answer(expr: &Expr) -> syn::Result<LitStr> {
+    match expr {
+        Expr::Lit(ExprLit { lit: Lit::Str(x), .. }) => Ok(x.clone()),
+        Expr::Lit(ExprLit { lit: Lit::Int(x), .. }) => Ok(LitStr::new(x.base10_digits(), x.span())),
+        Expr::Unary(x) if matches!(x.op, syn::UnOp::Neg(_)) => {
+            let x = answer(&x.expr)?;
+            Ok(LitStr::new(&format!("-{}", x.value()), x.span()))
+        }
+        _ => Err(syn::Error::new_spanned(expr, "expected a number or a string")),
+    }
+}
+
+impl Aoc {
+    fn parse(&mut self, meta: ParseNestedMeta) -> syn::Result<()> {
+        if meta.path.is_ident("day") {
+            let day: syn::LitInt = meta.value()?.parse()?;
+            match day.base10_parse::<u8>() {
+                Ok(x @ 1 ..= 25) => self.day = Some(x),
+                _ => return Err(syn::Error::new(day.span(), "expected a day from 1 to 25")),
+            }
+        } else if meta.path.is_ident("title") {
+            self.title = Some(meta.value()?.parse()?);
+        } else if meta.path.is_ident("tags") {
+            let tags: syn::ExprArray = meta.value()?.parse()?;
+            for tag in tags.elems {
+                match tag {
+                    Expr::Lit(ExprLit { lit: Lit::Str(x), .. }) => self.tags.push(x),
+                    tag => return Err(syn::Error::new_spanned(tag, "expected a string")),
+                }
+            }
+        } else if meta.path.is_ident("answers") {
+            let answers: syn::ExprTuple = meta.value()?.parse()?;
+            if answers.elems.len() != 2 {
+                return Err(syn::Error::new_spanned(answers, "expected the answers of part 1 and 2, e.g. (24000, 45000)"));
+            }
+            self.answers = Some((answer(&answers.elems[0])?, answer(&answers.elems[1])?));
+        } else {
+            return Err(meta.error("expected day, title, tags or answers"));
+        }
+        Ok(())
+    }
+
+    fn parse_attr(attr: &syn::Attribute) -> syn::Result<Aoc> {
+        let mut aoc = Aoc::default();
+        attr.parse_nested_meta(|meta| aoc.parse(meta))?;
+        Ok(aoc)
+    }
+}
+
+// Marks the solver of a day and attaches its metadata as 'META', see 'aoc_registry!'
+#[proc_macro_attribute]
+pub fn aoc(args: TokenStream, item: TokenStream) -> TokenStream {
+    let mut aoc = Aoc::default();
+    let parser = syn::meta::parser(|meta| aoc.parse(meta));
+    syn::parse_macro_input!(args with parser);
+    let item = syn::parse_macro_input!(item as ItemStruct);
+
+    let Some(day) = aoc.day else {
+        return syn::Error::new(Span::call_site(), "expected a day, e.g. #[aoc(day = 5)]")
+            .to_compile_error()
+            .into();
+    };
+
+    let ident = &item.ident;
+    let title = match &aoc.title {
+        Some(x) => quote!(Some(#x)),
+        None => quote!(None),
+    };
+    let tags = &aoc.tags;
+    let answers = match &aoc.answers {
+        Some((x, y)) => quote!([Some(#x), Some(#y)]),
+        None => quote!([None, None]),
+    };
+
+    quote! {
+        #item
+
+        impl #ident {
+            pub const META: crate::Meta = crate::Meta {
+                day: #day,
+                title: #title,
+                tags: &[#(#tags),*],
+                answers: #answers,
+            };
+        }
+    }.into()
+}
+
+// Day modules per year
+type Modules = Vec<(u16, Vec<Ident>)>;
+
+// A solver found by scanning 'src/year<year>/<module>/mod.rs'
+struct Day {
+    year: u16,
+    day: u8,
+    module: String,
+    solver: Ident,
+}
+
+fn sub_dirs(dir: &Path) -> Vec<(String, PathBuf)> {
+    let mut dirs: Vec<(String, PathBuf)> = fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter(|x| x.path().is_dir())
+        .filter_map(|x| Some((x.file_name().into_string().ok()?, x.path())))
+        .collect();
+    dirs.sort();
+    dirs
+}
+
+// Returns the year modules with their day modules, and the solvers that could be found.
+// A module that fails to parse is declared anyway so the compiler reports its errors.
+fn scan(src: &Path) -> Result<(Modules, Vec<Day>), String> {
+    let mut modules = Vec::new();
+    let mut days = Vec::new();
+    for (name, dir) in sub_dirs(src) {
+        let Some(year) = name.strip_prefix("year").and_then(|x| x.parse::<u16>().ok()) else {
+            continue;
+        };
+
+        let mut year_modules = Vec::new();
+        for (module, dir) in sub_dirs(&dir) {
+            let path = dir.join("mod.rs");
+            let Ok(source) = fs::read_to_string(&path) else {
+                continue;
+            };
+            year_modules.push(format_ident!("{}", module));
+            let Ok(file) = syn::parse_file(&source) else {
+                continue;
+            };
+
+            let solvers: Vec<(Ident, syn::Result<Aoc>)> = file.items
+                .iter()
+                .filter_map(|item| match item {
+                    Item::Struct(x) => Some(x),
+                    _ => None,
+                })
+                .flat_map(|x| x.attrs.iter().filter(|attr| attr.path().is_ident("aoc")).map(|attr| (x.ident.clone(), attr)))
+                .map(|(ident, attr)| (ident, Aoc::parse_attr(attr)))
+                .collect();
+            match &solvers[..] {
+                [(solver, Ok(Aoc { day: Some(day), .. }))] => days.push(Day { year, day: *day, module, solver: solver.clone() }),
+                // The attribute itself reports invalid arguments
+                [_] => {}
+                [] => return Err(format!("Oops, no solver found in {}, mark it with #[aoc(day = <day>)]", path.display())),
+                _ => return Err(format!("Oops, more than one solver found in {}", path.display())),
+            }
+        }
+        modules.push((year, year_modules));
+    }
+
+    days.sort_by_key(|x| (x.year, x.day));
+    if let Some(x) = days.windows(2).find(|x| (x[0].year, x[0].day) == (x[1].year, x[1].day)) {
+        return Err(format!("Oops, day {} of {} is registered by both {} and {}", x[0].day, x[0].year, x[0].module, x[1].module));
+    }
+    Ok((modules, days))
+}
+
+// Declares every 'src/year<year>/<module>' as a module, collects the solvers marked with '#[aoc(...)]'
+// into 'PROGS' and generates a test that checks the example answers of every day
+#[proc_macro]
+pub fn aoc_registry(_input: TokenStream) -> TokenStream {
+    let src = Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default()).join("src");
+    let (modules, days) = match scan(&src) {
+        Ok(x) => x,
+        Err(e) => return quote!(compile_error!(#e);).into(),
+    };
+
+    let mods = modules.iter().map(|(year, modules)| {
+        let year = format_ident!("year{}", year);
+        quote! {
+            pub mod #year { #(pub mod #modules;)* }
+        }
+    });
+
+    let progs = days.iter().map(|x| {
+        let (year, name, solver) = (x.year, &x.module, &x.solver);
+        let path = module_path(x);
+        quote! {
+            crate::Prog {
+                year: #year,
+                day: #path::#solver::META.day,
+                name: #name,
+                solve: crate::solver::solve::<#path::#solver>,
+                render: crate::solver::render::<#path::#solver>,
+                meta: &#path::#solver::META,
+            }
+        }
+    });
+
+    let test = |x: &Day| {
+        let (year, name, solver) = (x.year.to_string(), &x.module, &x.solver);
+        let path = module_path(x);
+        let test = format_ident!("{}", x.module);
+        quote! {
+            #[test]
+            fn #test() {
+                let dir = crate::utils::data_dir(None).join(#year).join(#name);
+                let checks = crate::check::check_examples(crate::solver::solve::<#path::#solver>, &dir).unwrap();
+                let failed: Vec<_> = checks.iter()
+                    .filter(|x| !x.passed())
+                    .map(|x| format!("{} part {}: {}", x.name, x.part, x.diff()))
+                    .collect();
+                assert!(failed.is_empty(), "{:#?}", failed);
+            }
+        }
+    };
+    let tests = modules.iter().map(|(year, _)| {
+        let tests = days.iter().filter(|x| x.year == *year).map(test);
+        let year = format_ident!("year{}", year);
+        quote! {
+            mod #year { #(#tests)* }
+        }
+    });
+
+    quote! {
+        #(#mods)*
+
+        pub static PROGS: &[crate::Prog] = &[#(#progs),*];
+
+        #[cfg(test)]
+        mod examples {
+            #(#tests)*
+        }
+    }.into()
+}
+
+fn module_path(day: &Day) -> TokenStream2 {
+    let year = format_ident!("year{}", day.year);
+    let module = format_ident!("{}", day.module);
+    quote!(crate::#year::#module)
+}
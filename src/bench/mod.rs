@@ -0,0 +1,125 @@
+use crate::error::{Diagnostics, Error};
+use crate::solver::{Part, SolveFn, Timings};
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Phase {
+    Parse,
+    PartOne,
+    PartTwo,
+    Total,
+}
+
+impl Phase {
+    fn duration(self, timings: &Timings) -> Option<Duration> {
+        match self {
+            Phase::Parse => Some(timings.parse),
+            Phase::PartOne => timings.part_one,
+            Phase::PartTwo => timings.part_two,
+            Phase::Total => Some(timings.total()),
+        }
+    }
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::PartOne => write!(f, "part1"),
+            Phase::PartTwo => write!(f, "part2"),
+            Phase::Total => write!(f, "total"),
+        }
+    }
+}
+
+impl FromStr for Phase {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Phase, String> {
+        match s {
+            "parse" => Ok(Phase::Parse),
+            "part1" => Ok(Phase::PartOne),
+            "part2" => Ok(Phase::PartTwo),
+            "total" => Ok(Phase::Total),
+            _ => Err(format!("expected parse, part1, part2 or total, got '{}'", s))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+}
+
+impl Stats {
+    fn from(mut samples: Vec<Duration>) -> Option<Stats> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        samples.sort();
+        let mean = samples.iter().sum::<Duration>() / samples.len() as u32;
+        Some(Stats {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            mean,
+        })
+    }
+}
+
+pub const DEFAULT_RUNS: usize = 10;
+
+// Solves the input 'runs' times and returns the statistics of every phase that was run
+pub fn bench(h: SolveFn, input: &str, part: Part, runs: usize, strict: bool) -> Result<Vec<(Phase, Stats)>, Error> {
+    let mut timings: Vec<Timings> = Vec::new();
+    for _ in 0 .. runs.max(1) {
+        let (_, x) = h(input, part, &mut Diagnostics::new(strict))?;
+        timings.push(x);
+    }
+
+    let stats = [Phase::Parse, Phase::PartOne, Phase::PartTwo, Phase::Total]
+        .into_iter()
+        .filter_map(|phase| {
+            let samples = timings.iter()
+                .filter_map(|x| phase.duration(x))
+                .collect();
+            Some((phase, Stats::from(samples)?))
+        })
+        .collect();
+    Ok(stats)
+}
+
+// Median duration per year, day and phase
+pub type Baseline = HashMap<(u16, u8, Phase), Duration>;
+
+// A baseline is stored one phase per line as '<year> <day> <phase> <median in nanoseconds>', e.g. '2022 7 part1 123456'
+pub fn load_baseline(path: &Path) -> Result<Baseline, Box<dyn std::error::Error>> {
+    let mut baseline = Baseline::new();
+    for line in fs::read_to_string(path)?.lines().filter(|line| !line.trim().is_empty()) {
+        let split: Vec<&str> = line.split_whitespace().collect();
+        match split[..] {
+            [year, day, phase, nanos] => {
+                let nanos: u64 = nanos.parse()?;
+                baseline.insert((year.parse()?, day.parse()?, phase.parse()?), Duration::from_nanos(nanos));
+            }
+            _ => return Err(format!("Invalid baseline entry '{}'", line).into())
+        }
+    }
+    Ok(baseline)
+}
+
+pub fn save_baseline(path: &Path, baseline: &Baseline) -> Result<(), Box<dyn std::error::Error>> {
+    let mut lines: Vec<String> = baseline.iter()
+        .map(|((year, day, phase), x)| format!("{} {} {} {}", year, day, phase, x.as_nanos()))
+        .collect();
+    lines.sort();
+    fs::write(path, lines.join("\n") + "\n")?;
+    Ok(())
+}
@@ -0,0 +1,110 @@
+use crate::error::Diagnostics;
+use crate::solver::{Answer, Answers, SolveFn};
+use crate::utils;
+use std::fs;
+use std::path::Path;
+
+#[derive(Debug)]
+pub struct Check {
+    pub name: String,
+    pub part: u8,
+    pub expected: Answer,
+    pub actual: Result<Answer, String>,
+}
+
+impl Check {
+    pub fn passed(&self) -> bool {
+        self.actual.as_ref() == Ok(&self.expected)
+    }
+
+    pub fn diff(&self) -> String {
+        match &self.actual {
+            Ok(x) if self.passed() => x.to_string(),
+            Ok(x) => format!("expected '{}', got '{}'", self.expected, x),
+            Err(e) => format!("expected '{}', got error: {}", self.expected, e),
+        }
+    }
+}
+
+// Runs the solver on the input file 'name' in 'dir' and compares the outcome with the answers in 'name.answers'
+pub fn check(h: SolveFn, dir: &Path, name: &str) -> Result<Vec<Check>, Box<dyn std::error::Error>> {
+    let expected: Answers = fs::read_to_string(dir.join(format!("{}.answers", name)))?.parse()?;
+    check_answers(h, dir, name, expected)
+}
+
+// Parsing is strict so a malformed line fails the check instead of silently changing the answer
+fn check_answers(h: SolveFn, dir: &Path, name: &str, expected: Answers) -> Result<Vec<Check>, Box<dyn std::error::Error>> {
+    let Some(part) = expected.part() else {
+        return Ok(Vec::new());
+    };
+
+    let path = dir.join(name);
+    let input = utils::read_input(&path)?;
+    let actual = h(&input, part, &mut Diagnostics::new(true))
+        .map(|(x, _)| x)
+        .map_err(|e| e.in_file(&path).to_string());
+    let checks = [(1, expected.part_one), (2, expected.part_two)]
+        .into_iter()
+        .filter_map(|(part, expected)| {
+            let expected = expected?;
+            let actual = actual.clone().and_then(|x| {
+                let answer = if part == 1 { x.part_one } else { x.part_two };
+                answer.ok_or(String::from("no answer"))
+            });
+            Some(Check { name: name.into(), part, expected, actual })
+        })
+        .collect();
+    Ok(checks)
+}
+
+// Example inputs are all files in 'dir' that come with a '.answers' file, except for the real input
+pub fn examples(dir: &Path) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+    let mut examples = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|x| x == "answers") {
+            let name = path.file_stem().unwrap().to_string_lossy().to_string();
+            if name != "input" && dir.join(&name).is_file() {
+                examples.push(name);
+            }
+        }
+    }
+
+    examples.sort();
+    Ok(examples)
+}
+
+pub fn check_examples(h: SolveFn, dir: &Path) -> Result<Vec<Check>, Box<dyn std::error::Error>> {
+    let mut checks = Vec::new();
+    for example in examples(dir)? {
+        checks.append(&mut check(h, dir, &example)?);
+    }
+    Ok(checks)
+}
+
+// Falls back to the 'answers' of the solver's metadata if none were recorded for the real input,
+// returns None if there are neither
+pub fn verify(h: SolveFn, dir: &Path, answers: Option<Answers>) -> Result<Option<Vec<Check>>, Box<dyn std::error::Error>> {
+    if dir.join("input.answers").is_file() {
+        return Ok(Some(check(h, dir, "input")?));
+    }
+    answers.map(|x| check_answers(h, dir, "input", x)).transpose()
+}
+
+// Stores the answers in 'name.answers', keeping previously recorded answers of parts that weren't solved
+pub fn record(dir: &Path, name: &str, answers: &Answers) -> Result<(), Box<dyn std::error::Error>> {
+    let path = dir.join(format!("{}.answers", name));
+    let mut recorded: Answers = match fs::read_to_string(&path) {
+        Ok(x) => x.parse()?,
+        Err(_) => Answers::default(),
+    };
+
+    if answers.part_one.is_some() {
+        recorded.part_one = answers.part_one.clone();
+    }
+    if answers.part_two.is_some() {
+        recorded.part_two = answers.part_two.clone();
+    }
+    fs::write(path, recorded.to_string())?;
+    Ok(())
+}
@@ -0,0 +1,163 @@
+use crate::error::Error;
+use crate::output::Format;
+use std::fs;
+use std::path::{Path, PathBuf};
+use toml::Value;
+
+pub const FILE_NAME: &str = ".aoc.toml";
+
+// Project defaults from '.aoc.toml', every setting is optional and command line flags take precedence, e.g.
+//
+//   year = 2022
+//   data_dir = "files"   # relative to the directory of the config file
+//   output = "table"
+//   runs = 10
+//   strict = false
+//   base_url = "https://adventofcode.com"
+//
+// The file is parsed as TOML, so strings may use escapes or single quotes. Only the top level settings
+// above are supported, there are no [tables].
+#[derive(Debug, Default, Clone)]
+pub struct Config {
+    // The file the settings were read from, None if no config file was found
+    pub path: Option<PathBuf>,
+    pub year: Option<u16>,
+    pub data_dir: Option<PathBuf>,
+    pub output: Option<Format>,
+    pub runs: Option<usize>,
+    pub strict: Option<bool>,
+    pub base_url: Option<String>,
+}
+
+// The line number and text of the line defining 'key', either 'key = ...' or '[key]', the first line if not found
+fn line_of<'a>(str: &'a str, key: &str) -> (usize, &'a str) {
+    let defines = |line: &str| {
+        let line = line.trim_start();
+        line.strip_prefix(key).is_some_and(|x| x.trim_start().starts_with('='))
+            || line.strip_prefix('[').and_then(|x| x.trim_start().strip_prefix(key)).is_some_and(|x| x.trim_start().starts_with(']'))
+    };
+    str.lines()
+        .enumerate()
+        .find(|(_, line)| defines(line))
+        .map_or((1, str.lines().next().unwrap_or_default()), |(i, line)| (i + 1, line))
+}
+
+impl Config {
+    // Looks for the config file in 'dir' and its parents
+    pub fn find(dir: &Path) -> Option<PathBuf> {
+        dir.ancestors()
+            .map(|x| x.join(FILE_NAME))
+            .find(|x| x.is_file())
+    }
+
+    pub fn load(path: &Path) -> Result<Config, Error> {
+        let str = fs::read_to_string(path).map_err(|e| Error::from(e).in_file(path))?;
+        let mut config = Config::parse(&str, path.parent().unwrap_or(Path::new(".")))
+            .map_err(|e| e.in_file(path))?;
+        config.path = Some(path.into());
+        Ok(config)
+    }
+
+    // The config of the current directory, the default config if there is none
+    pub fn discover() -> Result<Config, Error> {
+        let dir = std::env::current_dir()?;
+        Config::find(&dir).map_or(Ok(Config::default()), |path| Config::load(&path))
+    }
+
+    // A relative 'data_dir' is resolved against 'dir'
+    pub fn parse(str: &str, dir: &Path) -> Result<Config, Error> {
+        let table: toml::Table = str.parse().map_err(|e: toml::de::Error| {
+            let offset = e.span().map_or(0, |x| x.start.min(str.len()));
+            let i = str[.. offset].matches('\n').count();
+            Error::parse(i + 1, str.lines().nth(i).unwrap_or_default(), &format!("valid TOML, {}", e.message().trim_end()))
+        })?;
+
+        let mut config = Config::default();
+        for (key, value) in &table {
+            let error = |expected: &str| {
+                let (line, text) = line_of(str, key);
+                Error::parse(line, text, expected)
+            };
+            match (key.as_str(), value) {
+                ("year", Value::Integer(x)) => config.year = Some(u16::try_from(*x).map_err(|_| error("a year, e.g. year = 2022"))?),
+                ("data_dir", Value::String(x)) => config.data_dir = Some(dir.join(x)),
+                ("output", Value::String(x)) => config.output = Some(x.parse().map_err(|e: String| error(&e))?),
+                ("runs", Value::Integer(x)) => config.runs = Some(usize::try_from(*x).map_err(|_| error("a number of runs, e.g. runs = 10"))?),
+                ("strict", Value::Boolean(x)) => config.strict = Some(*x),
+                ("base_url", Value::String(x)) => config.base_url = Some(x.clone()),
+                ("year", _) => return Err(error("a year, e.g. year = 2022")),
+                ("data_dir", _) => return Err(error("a quoted path, e.g. data_dir = \"files\"")),
+                ("output", _) => return Err(error("a quoted format, e.g. output = \"table\"")),
+                ("runs", _) => return Err(error("a number of runs, e.g. runs = 10")),
+                ("strict", _) => return Err(error("true or false, e.g. strict = true")),
+                ("base_url", _) => return Err(error("a quoted URL, e.g. base_url = \"http://localhost:8000\"")),
+                _ => return Err(error("one of the top level settings year, data_dir, output, runs, strict or base_url, there are no [tables]")),
+            }
+        }
+        Ok(config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(str: &str) -> Result<Config, Error> {
+        Config::parse(str, Path::new("/project"))
+    }
+
+    // The line number of a parse error
+    fn error_line(str: &str) -> Option<usize> {
+        match parse(str) {
+            Err(Error::Parse { line, .. }) => Some(line),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn settings() {
+        let config = parse("year = 2022\noutput = \"csv\"\nruns = 5\nstrict = true\nbase_url = \"http://localhost:8000\"\n").unwrap();
+        assert_eq!(config.year, Some(2022));
+        assert_eq!(config.output, Some(Format::Csv));
+        assert_eq!(config.runs, Some(5));
+        assert_eq!(config.strict, Some(true));
+        assert_eq!(config.base_url.as_deref(), Some("http://localhost:8000"));
+        assert!(parse("").unwrap().year.is_none());
+    }
+
+    #[test]
+    fn comments() {
+        let config = parse("# defaults\nyear = 2022   # the event\nbase_url = \"http://localhost/#top\"  # a '#' in quotes\n").unwrap();
+        assert_eq!(config.year, Some(2022));
+        assert_eq!(config.base_url.as_deref(), Some("http://localhost/#top"));
+    }
+
+    #[test]
+    fn strings() {
+        assert_eq!(parse("data_dir = \"C:\\\\dir\"").unwrap().data_dir, Some(Path::new("/project").join("C:\\dir")));
+        assert_eq!(parse("data_dir = 'C:\\dir'").unwrap().data_dir, Some(Path::new("/project").join("C:\\dir")));
+    }
+
+    #[test]
+    fn data_dir() {
+        assert_eq!(parse("data_dir = \"files\"").unwrap().data_dir, Some(PathBuf::from("/project/files")));
+        assert_eq!(parse("data_dir = \"/data\"").unwrap().data_dir, Some(PathBuf::from("/data")));
+    }
+
+    #[test]
+    fn out_of_range() {
+        assert_eq!(error_line("year = 70000"), Some(1));
+        assert_eq!(error_line("year = -1"), Some(1));
+        assert_eq!(error_line("runs = -1"), Some(1));
+        assert_eq!(error_line("output = \"xml\""), Some(1));
+    }
+
+    #[test]
+    fn error_lines() {
+        assert_eq!(error_line("year = 2022\n\nruns = \"ten\"\n"), Some(3));
+        assert_eq!(error_line("year = 2022\nruns = \n"), Some(2));
+        assert_eq!(error_line("year = 2022\ndata_dir = \"files\n"), Some(2));
+        assert_eq!(error_line("year = 2022\ncolor = true\n"), Some(2));
+        assert_eq!(error_line("year = 2022\n\n[run]\nruns = 10\n"), Some(3));
+    }
+}
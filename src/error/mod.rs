@@ -0,0 +1,124 @@
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug)]
+pub enum Error {
+    Io {
+        file: Option<PathBuf>,
+        source: io::Error,
+    },
+    // Malformed input, 'line' and 'column' are 1-based
+    Parse {
+        file: Option<PathBuf>,
+        line: usize,
+        column: Option<usize>,
+        text: String,
+        expected: String,
+    },
+    // Well-formed input for which no answer can be found
+    Solve(String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    pub fn parse(line: usize, text: &str, expected: &str) -> Error {
+        Error::Parse {
+            file: None,
+            line,
+            column: None,
+            text: text.into(),
+            expected: expected.into(),
+        }
+    }
+
+    pub fn solve(message: &str) -> Error {
+        Error::Solve(message.into())
+    }
+
+    pub fn at_column(mut self, column: usize) -> Error {
+        if let Error::Parse { column: x, .. } = &mut self {
+            *x = Some(column);
+        }
+        self
+    }
+
+    pub fn in_file(mut self, path: &Path) -> Error {
+        match &mut self {
+            Error::Io { file, .. } | Error::Parse { file, .. } => *file = Some(path.into()),
+            Error::Solve(_) => {}
+        }
+        self
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Io { file: Some(file), source } => write!(f, "{}: {}", file.display(), source),
+            Error::Io { file: None, source } => write!(f, "{}", source),
+            Error::Parse { file, line, column, text, expected } => {
+                match file {
+                    Some(file) => write!(f, "{}:{}", file.display(), line)?,
+                    None => write!(f, "line {}", line)?,
+                }
+                if let Some(column) = column {
+                    write!(f, ":{}", column)?;
+                }
+                write!(f, ": unexpected '{}', expected {}", text, expected)
+            }
+            Error::Solve(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(source: io::Error) -> Error {
+        Error::Io { file: None, source }
+    }
+}
+
+// Collects malformed input, which is a hard error in strict mode and otherwise skipped with a warning
+#[derive(Debug, Default)]
+pub struct Diagnostics {
+    strict: bool,
+    warnings: Vec<Error>,
+}
+
+impl Diagnostics {
+    pub fn new(strict: bool) -> Diagnostics {
+        Diagnostics {
+            strict,
+            warnings: Vec::new(),
+        }
+    }
+
+    // Returns None if the result is a parse error that was skipped
+    pub fn report<T>(&mut self, result: Result<T>) -> Result<Option<T>> {
+        match result {
+            Err(e @ Error::Parse { .. }) if !self.strict => {
+                self.warnings.push(e);
+                Ok(None)
+            }
+            result => result.map(Some),
+        }
+    }
+
+    pub fn warnings(&self) -> &[Error] {
+        &self.warnings
+    }
+
+    pub fn into_warnings(self) -> Vec<Error> {
+        self.warnings
+    }
+}
@@ -0,0 +1,84 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+// Talks to the advent of code website, or to a stand-in server at 'base_url'
+pub struct Client {
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    // The session token is the 'session' cookie of a browser that is logged in to the website
+    pub fn new(base_url: &str) -> Result<Client, Box<dyn std::error::Error>> {
+        let session = env::var(SESSION_VAR)
+            .map_err(|_| format!("Oops, {} is not set, it should hold the session cookie of the website", SESSION_VAR))?;
+        Ok(Client {
+            base_url: base_url.trim_end_matches('/').into(),
+            session,
+        })
+    }
+
+    // URL of a puzzle page, e.g. '/input' for the input of the day
+    pub fn url(&self, year: u16, day: u8, page: &str) -> String {
+        format!("{}/{}/day/{}{}", self.base_url, year, day, page)
+    }
+
+    fn request(&self, method: &str, url: &str) -> ureq::Request {
+        ureq::request(method, url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION")))
+    }
+
+    pub fn get(&self, url: &str) -> Result<String, Box<dyn std::error::Error>> {
+        response(url, self.request("GET", url).call())
+    }
+
+    pub fn post(&self, url: &str, form: &[(&str, &str)]) -> Result<String, Box<dyn std::error::Error>> {
+        response(url, self.request("POST", url).send_form(form))
+    }
+}
+
+fn response(url: &str, response: Result<ureq::Response, ureq::Error>) -> Result<String, Box<dyn std::error::Error>> {
+    match response {
+        Ok(response) => Ok(response.into_string()?),
+        // The website explains errors in plain text, an HTML page only gets its status text
+        Err(ureq::Error::Status(status, response)) => {
+            let status_text = response.status_text().to_string();
+            let body = response.into_string().unwrap_or_default();
+            let message = body.lines()
+                .map(|x| x.trim())
+                .find(|x| !x.is_empty() && !x.starts_with('<'))
+                .filter(|_| !body.trim_start().starts_with('<'))
+                .unwrap_or(&status_text);
+            Err(format!("Oops, {} answered {}: {}", url, status, message).into())
+        }
+        Err(e) => Err(format!("Oops, failed to reach {}: {}", url, e).into()),
+    }
+}
+
+// An empty file is the placeholder created by 'aoc new', not a downloaded input
+pub fn is_cached(path: &Path) -> bool {
+    fs::metadata(path).is_ok_and(|x| x.len() > 0)
+}
+
+// Downloads the input of the day to 'path' unless it was downloaded before, returns whether it was downloaded
+pub fn fetch_input(client: &Client, year: u16, day: u8, path: &Path) -> Result<bool, Box<dyn std::error::Error>> {
+    if is_cached(path) {
+        return Ok(false);
+    }
+
+    let input = client.get(&client.url(year, day, "/input"))?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    // Written next to the input first so an interrupted download never looks like a cached input
+    let download = path.with_extension("download");
+    fs::write(&download, input)?;
+    fs::rename(&download, path)?;
+    Ok(true)
+}
@@ -0,0 +1,75 @@
+pub mod bench;
+pub mod check;
+pub mod config;
+pub mod error;
+pub mod fetch;
+pub mod output;
+pub mod scaffold;
+pub mod show;
+pub mod solver;
+pub mod submit;
+pub mod utils;
+pub mod watch;
+
+use advent_of_code_macros::aoc_registry;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use solver::{Answer, Answers, RenderFn, SolveFn};
+
+pub use advent_of_code_macros::aoc;
+
+// Every 'src/year<year>/<module>' is a day, its solver is marked with '#[aoc(day = <day>)]'
+aoc_registry!();
+
+// Metadata attached to a solver by '#[aoc(...)]'
+pub struct Meta {
+    pub day: u8,
+    pub title: Option<&'static str>,
+    pub tags: &'static [&'static str],
+    // Accepted answers of part 1 and 2 of the real input, an 'input.answers' file takes precedence
+    pub answers: [Option<&'static str>; 2],
+}
+
+impl Meta {
+    pub fn answers(&self) -> Option<Answers> {
+        let answer = |x: Option<&str>| x.map(Answer::from_str).and_then(Result::ok);
+        let answers = Answers {
+            part_one: answer(self.answers[0]),
+            part_two: answer(self.answers[1]),
+        };
+        answers.part().map(|_| answers)
+    }
+}
+
+pub struct Prog {
+    pub year: u16,
+    pub day: u8,
+    pub name: &'static str,
+    pub solve: SolveFn,
+    pub render: RenderFn,
+    pub meta: &'static Meta,
+}
+
+impl Prog {
+    // Directory holding the input and example files of this day
+    pub fn files_dir(&self, data_dir: &Path) -> PathBuf {
+        data_dir.join(self.year.to_string()).join(self.name)
+    }
+
+    // Directory holding the source of this day's solver, 'root' is the crate root
+    pub fn source_dir(&self, root: &Path) -> PathBuf {
+        root.join("src").join(format!("year{}", self.year)).join(self.name)
+    }
+}
+
+// Without a year all years are selected
+pub fn progs(year: Option<u16>, days: &RangeInclusive<u8>) -> Vec<&'static Prog> {
+    PROGS.iter()
+        .filter(|x| year.is_none_or(|year| x.year == year) && days.contains(&x.day))
+        .collect()
+}
+
+pub fn latest_year() -> Option<u16> {
+    PROGS.iter().map(|x| x.year).max()
+}
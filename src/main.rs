@@ -1,67 +1,813 @@
-mod utils;
-
-use lazy_static::lazy_static;
-use std::env;
+use advent_of_code::{bench, check, config, error, fetch, output, scaffold, show, solver, submit, utils, watch, Prog};
+use config::Config;
+use error::Diagnostics;
+use std::io::{self, IsTerminal};
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
 use clap::Parser;
+use solver::{Part, SolveFn};
+
+fn select_progs(year: Option<u16>, days: &RangeInclusive<u8>) -> Vec<&'static Prog> {
+    let progs = advent_of_code::progs(year, days);
+    if progs.is_empty() {
+        println!("Oops no result :-(...");
+    }
+    progs
+}
+
+// Lines skipped by lenient parsing are left in 'diag', see print_warnings
+fn run_prog(h: SolveFn, path: &Path, part: Part, diag: &mut Diagnostics) -> error::Result<(solver::Answers, solver::Timings)> {
+    let input = utils::read_input(path)?;
+    h(&input, part, diag).map_err(|e| e.in_file(path))
+}
+
+fn print_warnings(path: &Path, diag: &Diagnostics) {
+    diag.warnings()
+        .iter()
+        .for_each(|e| eprintln!("warning: {}: skipped {}", path.display(), e));
+}
+
+fn input_path(input: &InputArgs, data_dir: &Path, prog: &Prog) -> PathBuf {
+    input.input.clone()
+        .unwrap_or_else(|| prog.files_dir(data_dir).join(&input.variant))
+}
+
+// Offers to download the real inputs that are missing, only if someone is at the terminal to answer
+fn offer_fetch(progs: &[&Prog], input: &InputArgs, sources: &Sources) {
+    if input.input.is_some() || input.variant != "input" || !io::stdin().is_terminal() {
+        return;
+    }
+
+    for prog in progs {
+        let path = input_path(input, &sources.data_dir, prog);
+        if fetch::is_cached(&path) {
+            continue;
+        }
+
+        eprint!("The input of {} day {} is missing, fetch it? [y/N] ", prog.year, prog.day);
+        let mut answer = String::new();
+        if io::stdin().read_line(&mut answer).is_err() || !answer.trim().eq_ignore_ascii_case("y") {
+            continue;
+        }
+        match fetch::Client::new(&sources.base_url).and_then(|client| fetch::fetch_input(&client, prog.year, prog.day, &path)) {
+            Ok(_) => eprintln!("Fetched {}", path.display()),
+            Err(e) => eprintln!("{}", e),
+        }
+    }
+}
+
+fn run_progs(year: Option<u16>, days: RangeInclusive<u8>, part: Part, input: InputArgs, sources: &Sources, strict: bool, args: RunArgs) -> bool {
+    let progs = select_progs(year, &days);
+    if progs.is_empty() {
+        return false;
+    }
+    if input.input.is_some() && progs.len() > 1 {
+        println!("Oops, --input can only be used when running a single day");
+        return false;
+    }
+
+    // Every day runs on a single worker thread, results are reported in registry order
+    offer_fetch(&progs, &input, sources);
+    let jobs = args.jobs.unwrap_or_else(utils::default_jobs);
+    let results = utils::parallel_map(&progs, jobs, |prog| {
+        let path = input_path(&input, &sources.data_dir, prog);
+        let mut diag = Diagnostics::new(strict);
+        let text = match utils::read_input(&path) {
+            Ok(x) => x,
+            Err(e) => return (diag, Err(e), None),
+        };
+        let result = (prog.solve)(&text, part, &mut diag).map_err(|e| e.in_file(&path));
+        // Rendered from the same text, an input read from stdin can't be read twice
+        let renders = args.render
+            .then(|| (prog.render)(&text, part, &mut Diagnostics::new(strict)).map_err(|e| e.in_file(&path)));
+        (diag, result, renders)
+    });
+
+    let mut ok = true;
+    let mut records: Vec<output::Record> = Vec::new();
+    let mut renders = Vec::new();
+    for (prog, result) in progs.into_iter().zip(results) {
+        let path = input_path(&input, &sources.data_dir, prog);
+        let (diag, result, render) = result.unwrap_or_else(|e| (Diagnostics::new(strict), Err(error::Error::solve(&e)), None));
+        print_warnings(&path, &diag);
+        renders.extend(render.map(|x| (prog, x)));
+        let warnings = diag.warnings().len();
+        let record_of = |part, answer, duration, error| output::Record {
+            year: prog.year,
+            day: prog.day,
+            module: prog.name,
+            part,
+            answer,
+            duration,
+            warnings,
+            error,
+        };
+
+        match result {
+            Ok((answers, timings)) => {
+                if part.includes_one() {
+                    records.push(record_of(Some(1), answers.part_one.clone(), timings.part_one, None));
+                }
+                if part.includes_two() {
+                    records.push(record_of(Some(2), answers.part_two.clone(), timings.part_two, None));
+                }
+                if args.record {
+                    match check::record(&prog.files_dir(&sources.data_dir), &input.variant, &answers) {
+                        Ok(_) => eprintln!("Recorded the answers of {} day {}", prog.year, prog.day),
+                        Err(e) => {
+                            ok = false;
+                            eprintln!("Oops, failed to record the answers of {} day {}: {}", prog.year, prog.day, e);
+                        }
+                    }
+                }
+            }
+            Err(e) => {
+                ok = false;
+                records.push(record_of(None, None, None, Some(e.to_string())));
+            }
+        }
+    }
+
+    output::print(args.output.unwrap_or_default(), &records);
+    for (prog, render) in renders {
+        match render {
+            Ok(render) if render.is_empty() => println!("\nOops, {} day {} has nothing to render", prog.year, prog.day),
+            Ok(render) => render.into_iter().for_each(|(part, picture)| {
+                println!("\n{} day {} part {}:", prog.year, prog.day, if part == Part::One { 1 } else { 2 });
+                print!("{}", picture);
+            }),
+            Err(e) => {
+                ok = false;
+                println!("\nerror: {}", e);
+            }
+        }
+    }
+    ok
+}
+
+fn run_checks(year: Option<u16>, days: RangeInclusive<u8>, data_dir: &Path, verify: bool) -> bool {
+    let progs = select_progs(year, &days);
+    if progs.is_empty() {
+        return false;
+    }
+
+    let mut ok = true;
+    let mut rows: Vec<Vec<String>> = Vec::new();
+    for prog in progs {
+        let mut row = |input: &str, part: &str, answer: &str, status: &str| {
+            rows.push(vec![prog.year.to_string(), prog.day.to_string(), prog.name.into(), input.into(), part.into(), answer.into(), status.into()]);
+        };
+
+        let dir = prog.files_dir(data_dir);
+        let checks = match verify {
+            false => check::check_examples(prog.solve, &dir).map(Some),
+            true => check::verify(prog.solve, &dir, prog.meta.answers()),
+        };
+
+        match checks {
+            Ok(None) => row("input", "-", "-", "unverified"),
+            Ok(Some(checks)) if checks.is_empty() => row("-", "-", "-", "no examples"),
+            Ok(Some(checks)) => checks.into_iter().for_each(|x| {
+                ok &= x.passed();
+                row(&x.name, &x.part.to_string(), &x.diff(), if x.passed() { "pass" } else { "FAIL" });
+            }),
+            Err(e) => {
+                ok = false;
+                row("-", "-", "-", &format!("error: {}", e));
+            }
+        }
+    }
+
+    utils::print_table(&["Year", "Day", "Module", "Input", "Part", "Answer", "Status"], &rows);
+    ok
+}
+
+fn run_bench(year: Option<u16>, days: RangeInclusive<u8>, part: Part, input: InputArgs, sources: &Sources, strict: bool, args: BenchArgs) -> bool {
+    let progs = select_progs(year, &days);
+    if progs.is_empty() {
+        return false;
+    }
+    if input.input.is_some() && progs.len() > 1 {
+        println!("Oops, --input can only be used when running a single day");
+        return false;
+    }
+
+    let baseline = match args.baseline.as_deref().map(bench::load_baseline).transpose() {
+        Ok(x) => x,
+        Err(e) => {
+            println!("Oops, failed to load baseline: {}", e);
+            return false;
+        }
+    };
+
+    offer_fetch(&progs, &input, sources);
+    let mut ok = true;
+    let mut results = Vec::new();
+    let mut rows: Vec<Vec<String>> = Vec::new();
+    for prog in progs {
+        let path = input_path(&input, &sources.data_dir, prog);
+        let stats = utils::read_input(&path)
+            .and_then(|input| bench::bench(prog.solve, &input, part, args.runs.unwrap_or(bench::DEFAULT_RUNS), strict).map_err(|e| e.in_file(&path)));
+        match stats {
+            Ok(stats) => results.push((prog, stats)),
+            Err(e) => {
+                ok = false;
+                rows.push(vec![prog.year.to_string(), prog.day.to_string(), prog.name.into(), String::from("-"), String::from("-"),
+                    String::from("-"), String::from("-"), String::from("-"), format!("error: {}", e)]);
+            }
+        }
+    }
+
+    // Slowest days first
+    let total = |stats: &Vec<(bench::Phase, bench::Stats)>| stats.iter()
+        .find(|(phase, _)| *phase == bench::Phase::Total)
+        .map(|(_, x)| x.median);
+    results.sort_by_key(|(_, stats)| std::cmp::Reverse(total(stats)));
+
+    let mut medians = bench::Baseline::new();
+    for (prog, stats) in results {
+        for (phase, x) in stats {
+            let key = (prog.year, prog.day, phase);
+            let change = baseline.as_ref()
+                .and_then(|baseline| baseline.get(&key))
+                .map_or(String::from("-"), |base| {
+                    let change = (x.median.as_secs_f64() / base.as_secs_f64() - 1.0) * 100.0;
+                    format!("{:+.1}% (was {:.2?})", change, base)
+                });
+            rows.push(vec![prog.year.to_string(), prog.day.to_string(), prog.name.into(), phase.to_string(), format!("{:.2?}", x.min),
+                format!("{:.2?}", x.median), format!("{:.2?}", x.mean), change, String::from("ok")]);
+            medians.insert(key, x.median);
+        }
+    }
 
-macro_rules! count {
-    () => (0usize);
-    ($x:tt $($xs:tt)*) => (1usize + count!($($xs)*));
+    utils::print_table(&["Year", "Day", "Module", "Phase", "Min", "Median", "Mean", "Baseline", "Status"], &rows);
+
+    if let Some(path) = args.save_baseline {
+        if let Err(e) = bench::save_baseline(&path, &medians) {
+            println!("Oops, failed to save baseline: {}", e);
+            return false;
+        }
+    }
+    ok
 }
 
-macro_rules! files_dir {
-    ($module:ident) => {
-        env::current_dir().unwrap()
-            .display()
-            .to_string()
-        + &format!("/files/{}", String::from(stringify!($module)).to_lowercase())
+fn run_watch(year: Option<u16>, day: u8, part: Part, input: InputArgs, sources: &Sources, strict: bool, interval: Duration) -> bool {
+    let progs = select_progs(year, &(day ..= day));
+    let Some(prog) = progs.first() else {
+        return false;
+    };
+
+    let exe = match std::env::current_exe() {
+        Ok(x) => x,
+        Err(e) => {
+            println!("Oops, failed to locate the running binary: {}", e);
+            return false;
+        }
     };
+
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    offer_fetch(&progs, &input, sources);
+    let path = input_path(&input, &sources.data_dir, prog);
+    let inputs = vec![prog.files_dir(&sources.data_dir), path.clone()];
+    let sources = vec![prog.source_dir(root)];
+    println!("Watching {} and {} for day {} of {}, press Ctrl-C to stop", inputs[0].display(), sources[0].display(), prog.day, prog.year);
+
+    let mut previous = watch::previous();
+    let mut input_files = watch::snapshot(&inputs);
+    let mut source_files = watch::snapshot(&sources);
+    let mut run = true;
+    loop {
+        if run {
+            let mut diag = Diagnostics::new(strict);
+            let result = run_prog(prog.solve, &path, part, &mut diag);
+            print_warnings(&path, &diag);
+            match result {
+                Ok((answers, timings)) => {
+                    watch::diff(previous.as_ref(), &answers).iter().for_each(|x| println!("{}", x));
+                    let part_time = |x: Option<Duration>| x.map_or(String::from("-"), |x| format!("{:.2?}", x));
+                    println!("parse {:.2?}, part 1 {}, part 2 {}, total {:.2?}, {} warnings",
+                        timings.parse, part_time(timings.part_one), part_time(timings.part_two), timings.total(), diag.warnings().len());
+                    previous = Some(answers);
+                }
+                Err(e) => println!("error: {}", e),
+            }
+        }
+
+        thread::sleep(interval);
+        let files = watch::snapshot(&sources);
+        let changes = watch::changes(&source_files, &files);
+        if !changes.is_empty() {
+            source_files = files;
+            changes.iter().for_each(|x| println!("Changed {}, rebuilding", x.display()));
+            match watch::rebuild(root) {
+                Ok(true) => if let Err(e) = watch::restart(&exe, previous.as_ref()) {
+                    println!("Oops, failed to restart: {}", e);
+                    return false;
+                },
+                Ok(false) => println!("Oops, the build failed, waiting for the next change"),
+                Err(e) => println!("Oops, failed to run cargo: {}", e),
+            }
+            run = false;
+            continue;
+        }
+
+        let files = watch::snapshot(&inputs);
+        let changes = watch::changes(&input_files, &files);
+        changes.iter().for_each(|x| println!("Changed {}", x.display()));
+        run = !changes.is_empty();
+        input_files = files;
+    }
 }
 
-macro_rules! aoc_progs {
-    ($(day($day:literal) = $module:ident),+) => {
-        $(mod $module;)+
-        lazy_static! {
-            static ref PROGS: [(u8, fn(String) -> Result<(), Box<dyn std::error::Error>>, String); count!($($module)+)] = [
-                $(($day, $module::solve, files_dir!($module)),)+
-            ];
+fn run_submit(year: Option<u16>, day: u8, part: u8, sources: &Sources, force: bool) -> bool {
+    let progs = select_progs(year, &(day ..= day));
+    let Some(prog) = progs.first() else {
+        return false;
+    };
+
+    // Only the answer of the real input is ever submitted, there is no --input or --variant. Parsing is
+    // strict, an answer from a partially parsed input is likely wrong.
+    let input = InputArgs { input: None, variant: String::from("input") };
+    offer_fetch(&progs, &input, sources);
+    let path = input_path(&input, &sources.data_dir, prog);
+    let answer = match run_prog(prog.solve, &path, if part == 1 { Part::One } else { Part::Two }, &mut Diagnostics::new(true)) {
+        Ok((answers, _)) => answers.part_one.or(answers.part_two).unwrap(),
+        Err(e) => {
+            println!("error: {}", e);
+            return false;
         }
+    };
+
+    let history_path = prog.files_dir(&sources.data_dir).join("submissions");
+    let result = submit::load_history(&history_path).and_then(|history| {
+        let warnings = submit::precheck(&history, part, &answer, submit::now())?;
+        warnings.iter().for_each(|x| println!("warning: {}", x));
+        if !warnings.is_empty() && !force {
+            return Err(format!("Oops, not submitting {}, use --force to submit it anyway", answer).into());
+        }
+
+        let client = fetch::Client::new(&sources.base_url)?;
+        let verdict = submit::submit(&client, prog.year, prog.day, part, &answer)?;
+        // The answer was posted, a failure to record it must not hide the verdict
+        let submission = submit::Submission { time: submit::now(), part, verdict, answer: answer.clone() };
+        if let Err(e) = submit::append_history(&history_path, &submission) {
+            println!("Oops, failed to record the submission in {}: {}", history_path.display(), e);
+        }
+        Ok(verdict)
+    });
+
+    let ok = matches!(result, Ok(submit::Verdict::Correct));
+    match result {
+        Ok(submit::Verdict::Correct) => println!("{} is the right answer of {} day {} part {}", answer, prog.year, prog.day, part),
+        Ok(submit::Verdict::RateLimited(wait)) => println!("Oops, {} was not accepted yet, wait {}s and submit again", answer, wait),
+        Ok(submit::Verdict::AlreadySolved) => println!("Part {} of {} day {} was already solved", part, prog.year, prog.day),
+        Ok(verdict) => println!("{} is not the right answer: {}", answer, verdict.to_string().replace('_', " ")),
+        Err(e) => println!("{}", e),
     }
+    ok
 }
 
-aoc_progs!
-{
-    day(1) = elves_calories,
-    day(2) = rock_paper_scissors,
-    day(3) = rucksack_reorganization,
-    day(4) = camp_cleanup,
-    day(5) = supply_stacks,
-    day(6) = tuning_trouble,
-    day(7) = no_space_left_on_device
+// A year, or every year for 'all'
+#[derive(Debug, Clone, Copy)]
+enum Years {
+    All,
+    Only(u16),
 }
 
-fn run_prog(day: u8) {
-    let prog = PROGS.iter()
-        .find(|t| t.0 == day)
-        .map(|t| Some((t.1, t.2.clone())));
-    let Some(prog) = prog else {
-        println!("Oops no result :-(...");
-        return;
+fn parse_years(arg: &str) -> Result<Years, String> {
+    match arg {
+        "all" => Ok(Years::All),
+        _ => arg.parse().map(Years::Only).map_err(|_| format!("expected a year (e.g. 2022) or 'all', got '{}'", arg)),
+    }
+}
+
+fn parse_days(arg: &str) -> Result<RangeInclusive<u8>, String> {
+    let range = if let Some((x, y)) = arg.split_once("..=") {
+        x.parse::<u8>().ok().zip(y.parse::<u8>().ok()).map(|(x, y)| x ..= y)
+    } else if let Some((x, y)) = arg.split_once("..") {
+        x.parse::<u8>().ok().zip(y.parse::<u8>().ok()).filter(|(x, y)| x < y).map(|(x, y)| x ..= y - 1)
+    } else {
+        arg.parse::<u8>().ok().map(|x| x ..= x)
     };
 
-    let (h, files_dir) = prog.unwrap();
-    h(files_dir).unwrap();
+    range.ok_or(format!("expected a day or a range of days (e.g. 5, 3..=7), got '{}'", arg))
 }
 
 #[derive(Parser, Debug)]
-#[command(author, version, about, long_about = None)]
+#[command(author, version, about, long_about = None, subcommand_negates_reqs = true)]
 struct Args {
-   // Advent of code day
-   #[arg(short, long, required = true)]
-   day: u8,
+   #[command(subcommand)]
+   command: Option<Command>,
+
+   /// Advent of code event or 'all', defaults to the year of the config file, else the latest year when selecting days
+   #[arg(short, long, value_parser = parse_years)]
+   year: Option<Years>,
+
+   /// Advent of code day or range of days, e.g. 5, 3..8 or 3..=7
+   #[arg(short, long, value_parser = parse_days, required_unless_present_any = ["all", "check", "verify", "bench"], conflicts_with = "all")]
+   day: Option<RangeInclusive<u8>>,
+
+   /// Run every registered day
+   #[arg(short, long)]
+   all: bool,
+
+   /// Run the selected days, or every day, on their example inputs and compare the expected answers
+   #[arg(short, long, conflicts_with_all = ["verify", "input", "variant", "part", "strict", "lenient"])]
+   check: bool,
+
+   /// Run the selected days, or every day, on their real input and compare the recorded answers
+   #[arg(long, conflicts_with_all = ["input", "variant", "part", "strict", "lenient"])]
+   verify: bool,
+
+   #[command(flatten)]
+   parsing: ParsingArgs,
+
+   /// Puzzle part to run: 1, 2 or both
+   #[arg(short, long, default_value = "both")]
+   part: Part,
+
+   #[command(flatten)]
+   input: InputArgs,
+
+   #[command(flatten)]
+   sources: SourceArgs,
+
+   #[command(flatten)]
+   run: RunArgs,
+
+   #[command(flatten)]
+   bench: BenchArgs,
+}
+
+#[derive(clap::Subcommand, Debug)]
+enum Command {
+   /// List the registered days with their metadata
+   List {
+      /// Advent of code event, all years if not given
+      #[arg(short, long)]
+      year: Option<u16>,
+   },
+
+   /// Scaffold a new day from the template and add it to the registry
+   New {
+      /// Advent of code event, defaults to the latest year
+      #[arg(short, long)]
+      year: Option<u16>,
+
+      /// Advent of code day
+      #[arg(short, long)]
+      day: u8,
+
+      /// Module name of the solver, e.g. treetop_tree_house
+      #[arg(short, long)]
+      name: String,
+
+      #[command(flatten)]
+      sources: SourceArgs,
+   },
+
+   /// Re-run a day whenever its input files change, rebuild and restart when its source changes
+   Watch {
+      /// Advent of code event, defaults to the latest year
+      #[arg(short, long)]
+      year: Option<u16>,
+
+      /// Advent of code day
+      #[arg(short, long)]
+      day: u8,
+
+      /// Puzzle part to run: 1, 2 or both
+      #[arg(short, long, default_value = "both")]
+      part: Part,
+
+      #[command(flatten)]
+      parsing: ParsingArgs,
+
+      /// Polling interval in milliseconds
+      #[arg(long, default_value_t = 500)]
+      interval: u64,
+
+      #[command(flatten)]
+      input: InputArgs,
+
+      #[command(flatten)]
+      sources: SourceArgs,
+   },
+
+   /// Download the inputs of the selected days, inputs that were downloaded before are kept
+   Fetch {
+      /// Advent of code event, defaults to the latest year
+      #[arg(short, long)]
+      year: Option<u16>,
+
+      /// Advent of code day or range of days, e.g. 5, 3..8 or 3..=7
+      #[arg(short, long, value_parser = parse_days)]
+      day: RangeInclusive<u8>,
+
+      #[command(flatten)]
+      sources: SourceArgs,
+   },
+
+   /// Solve a part of a day and submit the answer, unless the submission history shows it is wrong
+   Submit {
+      /// Advent of code event, defaults to the latest year
+      #[arg(short, long)]
+      year: Option<u16>,
+
+      /// Advent of code day
+      #[arg(short, long)]
+      day: u8,
+
+      /// Puzzle part to submit the answer of: 1 or 2
+      #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+      part: u8,
+
+      /// Submit even if the answer is outside the bounds of earlier too high or too low answers
+      #[arg(long)]
+      force: bool,
+
+      #[command(flatten)]
+      sources: SourceArgs,
+   },
+
+   /// Print the puzzle description saved as 'puzzle.html' in the day's data directory
+   Show {
+      /// Advent of code event, defaults to the latest year
+      #[arg(short, long)]
+      year: Option<u16>,
+
+      /// Advent of code day
+      #[arg(short, long)]
+      day: u8,
+
+      /// Maximum line length of the text
+      #[arg(long, default_value_t = 80)]
+      width: usize,
+
+      #[command(flatten)]
+      sources: SourceArgs,
+   },
+
+   /// Inspect the settings of the '.aoc.toml' config file
+   Config {
+      #[command(subcommand)]
+      command: ConfigCommand,
+   },
+}
+
+#[derive(clap::Subcommand, Debug)]
+enum ConfigCommand {
+   /// Print the effective settings and where they come from
+   Show,
+}
+
+#[derive(clap::Args, Debug)]
+struct ParsingArgs {
+   /// Fail on any input line that cannot be parsed instead of skipping it with a warning
+   #[arg(long)]
+   strict: bool,
+
+   /// Skip input lines that cannot be parsed with a warning, even if the config file sets strict mode
+   #[arg(long, conflicts_with = "strict")]
+   lenient: bool,
+}
+
+impl ParsingArgs {
+    fn strict(&self, config: &Config) -> bool {
+        self.strict || (!self.lenient && config.strict.unwrap_or(false))
+    }
+}
+
+#[derive(clap::Args, Debug)]
+struct InputArgs {
+   /// Input file to use instead of the one in the data directory, '-' reads from stdin
+   #[arg(short, long, conflicts_with = "variant")]
+   input: Option<PathBuf>,
+
+   /// Input file name to pick from the day's data directory, e.g. 'test'
+   #[arg(long, default_value = "input")]
+   variant: String,
+}
+
+#[derive(clap::Args, Debug)]
+struct SourceArgs {
+   /// Directory holding a '<year>/<module>' sub directory with input files for every day
+   #[arg(long, env = "AOC_DATA_DIR")]
+   data_dir: Option<PathBuf>,
+
+   /// Website to fetch inputs from and submit answers to, e.g. a local stand-in server
+   #[arg(long, env = "AOC_BASE_URL")]
+   base_url: Option<String>,
+}
+
+// Where the input files are and where missing inputs are fetched from
+struct Sources {
+    data_dir: PathBuf,
+    base_url: String,
+}
+
+impl SourceArgs {
+    // Settings missing from the command line are taken from the config file, else the defaults
+    fn merge(self, config: &Config) -> Sources {
+        Sources {
+            data_dir: utils::data_dir(self.data_dir.or(config.data_dir.clone())),
+            base_url: self.base_url.or(config.base_url.clone()).unwrap_or(String::from(fetch::DEFAULT_BASE_URL)),
+        }
+    }
+}
+
+#[derive(clap::Args, Debug)]
+struct RunArgs {
+   /// Record the answers as accepted answers of the input, see --verify
+   #[arg(long, conflicts_with_all = ["check", "verify", "input"])]
+   record: bool,
+
+   /// Number of days solved at once, defaults to the number of CPU cores
+   #[arg(short, long)]
+   jobs: Option<usize>,
+
+   /// Output format of the answers: table, json or csv, defaults to table
+   #[arg(short, long)]
+   output: Option<output::Format>,
+
+   /// Print a picture of how each part is solved after the answers, for the days that draw one
+   #[arg(long, conflicts_with_all = ["check", "verify", "bench"])]
+   render: bool,
+}
+
+#[derive(clap::Args, Debug)]
+struct BenchArgs {
+   /// Time parsing, part 1 and part 2 of the selected days, or every day
+   #[arg(short, long, conflicts_with_all = ["check", "verify", "record"])]
+   bench: bool,
+
+   /// Number of benchmark runs per day, defaults to 10
+   #[arg(long, requires = "bench")]
+   runs: Option<usize>,
+
+   /// Save the median timings of this benchmark to a file
+   #[arg(long, requires = "bench")]
+   save_baseline: Option<PathBuf>,
+
+   /// Compare the median timings of this benchmark with a previously saved baseline
+   #[arg(long, requires = "bench")]
+   baseline: Option<PathBuf>,
+}
+
+fn show_config(config: &Config) {
+    let source = |configured: bool| String::from(if configured { "config" } else { "default" });
+    let data_dir = std::env::var_os("AOC_DATA_DIR").map(|x| (PathBuf::from(x), String::from("env AOC_DATA_DIR")))
+        .or_else(|| config.data_dir.clone().map(|x| (x, source(true))))
+        .unwrap_or_else(|| (utils::data_dir(None), source(false)));
+    let base_url = std::env::var("AOC_BASE_URL").ok().map(|x| (x, String::from("env AOC_BASE_URL")))
+        .or_else(|| config.base_url.clone().map(|x| (x, source(true))))
+        .unwrap_or_else(|| (String::from(fetch::DEFAULT_BASE_URL), source(false)));
+    let year = config.year.or_else(advent_of_code::latest_year)
+        .map_or(String::from("-"), |x| x.to_string());
+
+    let rows = vec![
+        vec![String::from("file"), config.path.as_ref().map_or(String::from("-"), |x| x.display().to_string()), String::from("-")],
+        vec![String::from("year"), year, source(config.year.is_some())],
+        vec![String::from("data_dir"), data_dir.0.display().to_string(), data_dir.1],
+        vec![String::from("output"), config.output.unwrap_or_default().to_string(), source(config.output.is_some())],
+        vec![String::from("runs"), config.runs.unwrap_or(bench::DEFAULT_RUNS).to_string(), source(config.runs.is_some())],
+        vec![String::from("strict"), config.strict.unwrap_or(false).to_string(), source(config.strict.is_some())],
+        vec![String::from("base_url"), base_url.0, base_url.1],
+    ];
+    utils::print_table(&["Setting", "Value", "Source"], &rows);
+    match config.year {
+        Some(_) => println!("\nCommands without --year use this year, except list, '--year all' selects every year again"),
+        None => println!("\nWithout --year, selecting days picks the latest year and --all, --check, --verify or --bench every year"),
+    }
+}
+
+// Settings missing from the command line are taken from the config file
+fn run_command(command: Command, config: &Config) -> bool {
+    match command {
+        Command::Config { command: ConfigCommand::Show } => {
+            show_config(config);
+            true
+        }
+        Command::List { year } => {
+            let progs = select_progs(year, &(u8::MIN ..= u8::MAX));
+            let rows: Vec<Vec<String>> = progs.iter()
+                .map(|x| vec![x.year.to_string(), x.day.to_string(), x.name.into(), x.meta.title.unwrap_or("-").into(),
+                    x.meta.tags.join(", "), x.meta.answers().map_or(String::from("-"), |x| x.to_string().trim().replace('\n', ", "))])
+                .collect();
+            utils::print_table(&["Year", "Day", "Module", "Title", "Tags", "Answers"], &rows);
+            !progs.is_empty()
+        }
+        Command::New { year, day, name, sources } => {
+            let year = year.or(config.year).or_else(advent_of_code::latest_year).unwrap();
+            let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+            match scaffold::new_day(root, &sources.merge(config).data_dir, year, day, &name) {
+                Ok(files) => {
+                    files.iter().for_each(|x| println!("Created {}", x.display()));
+                    println!("Day {} of {} is registered as {} on the next build", day, year, name);
+                    true
+                }
+                Err(e) => {
+                    println!("{}", e);
+                    false
+                }
+            }
+        }
+        Command::Fetch { year, day, sources } => {
+            let progs = select_progs(year.or(config.year).or_else(advent_of_code::latest_year), &day);
+            let sources = sources.merge(config);
+            let client = match fetch::Client::new(&sources.base_url) {
+                Ok(x) => x,
+                Err(e) => {
+                    println!("{}", e);
+                    return false;
+                }
+            };
+
+            let mut ok = !progs.is_empty();
+            for prog in progs {
+                let path = prog.files_dir(&sources.data_dir).join("input");
+                match fetch::fetch_input(&client, prog.year, prog.day, &path) {
+                    Ok(true) => println!("Fetched {}", path.display()),
+                    Ok(false) => println!("Cached {}", path.display()),
+                    Err(e) => {
+                        ok = false;
+                        println!("{}", e);
+                    }
+                }
+            }
+            ok
+        }
+        Command::Submit { year, day, part, force, sources } => {
+            let year = year.or(config.year).or_else(advent_of_code::latest_year);
+            run_submit(year, day, part, &sources.merge(config), force)
+        }
+        Command::Show { year, day, width, sources } => {
+            let year = year.or(config.year).or_else(advent_of_code::latest_year);
+            let Some(prog) = select_progs(year, &(day ..= day)).into_iter().next() else {
+                return false;
+            };
+
+            let path = prog.files_dir(&sources.merge(config).data_dir).join("puzzle.html");
+            match std::fs::read_to_string(&path) {
+                Ok(html) => {
+                    println!("{}", show::render(&html, width, io::stdout().is_terminal()));
+                    true
+                }
+                Err(e) => {
+                    println!("Oops, no puzzle description in {}, save the puzzle page there: {}", path.display(), e);
+                    false
+                }
+            }
+        }
+        Command::Watch { year, day, part, parsing, interval, input, sources } => {
+            let year = year.or(config.year).or_else(advent_of_code::latest_year);
+            run_watch(year, day, part, input, &sources.merge(config), parsing.strict(config), Duration::from_millis(interval))
+        }
+    }
 }
 
 fn main() {
-    let args = Args::parse();
-    run_prog(args.day);
+    let mut args = Args::parse();
+    let config = match Config::discover() {
+        Ok(x) => x,
+        Err(e) => {
+            println!("Oops, failed to read the config file: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    if let Some(command) = args.command {
+        if !run_command(command, &config) {
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    let strict = args.parsing.strict(&config);
+    let sources = args.sources.merge(&config);
+    args.run.output = args.run.output.or(config.output);
+    // Pictures on stdout would break json and csv output, '--output table' overrides the config file
+    if let Some(format) = args.run.output.filter(|x| args.run.render && *x != output::Format::Table) {
+        println!("Oops, --render only works with the table output, not {}", format);
+        std::process::exit(1);
+    }
+    args.bench.runs = args.bench.runs.or(config.runs);
+    let year = match args.year {
+        Some(Years::All) => None,
+        Some(Years::Only(x)) => Some(x),
+        None => config.year.or_else(|| args.day.as_ref().and_then(|_| advent_of_code::latest_year())),
+    };
+    let days = args.day.unwrap_or(u8::MIN ..= u8::MAX);
+    let ok = if args.check || args.verify {
+        run_checks(year, days, &sources.data_dir, args.verify)
+    } else if args.bench.bench {
+        run_bench(year, days, args.part, args.input, &sources, strict, args.bench)
+    } else {
+        run_progs(year, days, args.part, args.input, &sources, strict, args.run)
+    };
+    if !ok {
+        std::process::exit(1);
+    }
 }
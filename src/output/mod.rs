@@ -0,0 +1,152 @@
+use crate::solver::Answer;
+use crate::utils;
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Table,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Format, String> {
+        match s {
+            "table" => Ok(Format::Table),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("expected table, json or csv, got '{}'", s))
+        }
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Format::Table => write!(f, "table"),
+            Format::Json => write!(f, "json"),
+            Format::Csv => write!(f, "csv"),
+        }
+    }
+}
+
+// Outcome of a single puzzle part, or of a whole day if it failed before any part was solved
+#[derive(Debug, Clone)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub module: &'static str,
+    pub part: Option<u8>,
+    pub answer: Option<Answer>,
+    pub duration: Option<Duration>,
+    // Number of input lines skipped by lenient parsing
+    pub warnings: usize,
+    pub error: Option<String>,
+}
+
+pub fn print(format: Format, records: &[Record]) {
+    match format {
+        Format::Table => print_table(records),
+        Format::Json => print_json(records),
+        Format::Csv => print_csv(records),
+    }
+}
+
+fn print_table(records: &[Record]) {
+    let mut rows: Vec<Vec<String>> = Vec::new();
+    for record in records {
+        let row = match rows.last_mut() {
+            Some(row) if row[0] == record.year.to_string() && row[1] == record.day.to_string() => row,
+            _ => {
+                rows.push(vec![record.year.to_string(), record.day.to_string(), record.module.into(), "-".into(), "-".into(),
+                    record.warnings.to_string(), "ok".into()]);
+                rows.last_mut().unwrap()
+            }
+        };
+
+        if let (Some(part), Some(answer)) = (record.part, &record.answer) {
+            row[2 + part as usize] = answer.to_string();
+        }
+        if let Some(e) = &record.error {
+            row[6] = format!("error: {}", e);
+        }
+    }
+
+    utils::print_table(&["Year", "Day", "Module", "Part 1", "Part 2", "Warnings", "Status"], &rows);
+}
+
+fn millis(duration: Duration) -> String {
+    format!("{:.3}", duration.as_secs_f64() * 1000.0)
+}
+
+fn json_string(s: &str) -> String {
+    let mut escaped = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+fn print_json(records: &[Record]) {
+    let objects: Vec<String> = records.iter()
+        .map(|x| {
+            let answer = match &x.answer {
+                Some(Answer::Number(x)) => x.to_string(),
+                Some(Answer::Text(x)) => json_string(x),
+                None => String::from("null"),
+            };
+            format!("  {{\"year\": {}, \"day\": {}, \"module\": {}, \"part\": {}, \"answer\": {}, \"duration_ms\": {}, \"warnings\": {}, \"error\": {}}}",
+                x.year,
+                x.day,
+                json_string(x.module),
+                x.part.map_or(String::from("null"), |x| x.to_string()),
+                answer,
+                x.duration.map_or(String::from("null"), millis),
+                x.warnings,
+                x.error.as_deref().map_or(String::from("null"), json_string))
+        })
+        .collect();
+
+    if objects.is_empty() {
+        println!("[]");
+    } else {
+        println!("[\n{}\n]", objects.join(",\n"));
+    }
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.into()
+    }
+}
+
+fn print_csv(records: &[Record]) {
+    println!("year,day,module,part,answer,duration_ms,warnings,error");
+    records.iter().for_each(|x| {
+        println!("{},{},{},{},{},{},{},{}",
+            x.year,
+            x.day,
+            csv_field(x.module),
+            x.part.map_or(String::new(), |x| x.to_string()),
+            x.answer.as_ref().map_or(String::new(), |x| csv_field(&x.to_string())),
+            x.duration.map_or(String::new(), millis),
+            x.warnings,
+            x.error.as_deref().map_or(String::new(), csv_field));
+    });
+}
@@ -0,0 +1,46 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn is_module_name(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_lowercase()) &&
+    name.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+}
+
+// Creates the module from the template with the day filled in and the files directory with placeholder
+// input and example files. The day registers itself on the next build. Returns the created files.
+pub fn new_day(root: &Path, data_dir: &Path, year: u16, day: u8, name: &str) -> Result<Vec<PathBuf>, Box<dyn std::error::Error>> {
+    if !is_module_name(name) {
+        return Err(format!("Oops, '{}' is not a valid module name, use snake_case", name).into());
+    }
+
+    if !(1 ..= 25).contains(&day) {
+        return Err(format!("Oops, day {} is not a day of advent, expected 1 to 25", day).into());
+    }
+    if !crate::progs(Some(year), &(day ..= day)).is_empty() {
+        return Err(format!("Oops, day {} of {} is already registered", day, year).into());
+    }
+
+    let module_dir = root.join("src").join(format!("year{}", year)).join(name);
+    let files_dir = data_dir.join(year.to_string()).join(name);
+    for dir in [&module_dir, &files_dir] {
+        if dir.exists() {
+            return Err(format!("Oops, '{}' already exists", dir.display()).into());
+        }
+    }
+
+    let template = fs::read_to_string(root.join("src").join("template.rs"))?
+        .replace("#[aoc(day = 0)]", &format!("#[aoc(day = {})]", day));
+
+    let files = [
+        (module_dir.join("mod.rs"), template),
+        (files_dir.join("input"), String::new()),
+        (files_dir.join("example"), String::new()),
+        (files_dir.join("example.answers"), String::new()),
+    ];
+    fs::create_dir_all(&module_dir)?;
+    fs::create_dir_all(&files_dir)?;
+    for (path, contents) in &files {
+        fs::write(path, contents)?;
+    }
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
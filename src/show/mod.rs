@@ -0,0 +1,302 @@
+// Renders the HTML of a puzzle page as terminal text. Only the subset the puzzle pages use is supported:
+// headings, paragraphs, lists, code blocks, inline code, emphasis and links, everything else is rendered as text.
+
+const BOLD: &str = "\x1b[1m";
+const HIGHLIGHT: &str = "\x1b[1;97m";
+const CODE: &str = "\x1b[36m";
+const RESET: &str = "\x1b[0m";
+
+enum Token<'a> {
+    Open(String),
+    Close(String),
+    Text(&'a str),
+}
+
+fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut rest = html;
+    while !rest.is_empty() {
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.split_once("-->").map_or("", |(_, x)| x);
+        } else if rest.starts_with('<') {
+            let (tag, next) = rest[1..].split_once('>').unwrap_or((&rest[1..], ""));
+            let name = tag.trim_start_matches('/')
+                .split(|c: char| c.is_whitespace() || c == '/')
+                .next()
+                .unwrap_or_default()
+                .to_ascii_lowercase();
+            tokens.push(if tag.starts_with('/') { Token::Close(name) } else { Token::Open(name) });
+            rest = next;
+        } else {
+            let end = rest.find('<').unwrap_or(rest.len());
+            tokens.push(Token::Text(&rest[..end]));
+            rest = &rest[end..];
+        }
+    }
+    tokens
+}
+
+fn decode_entities(text: &str) -> String {
+    let mut decoded = String::new();
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let entity = rest[1..].split_once(';')
+            .map(|(x, _)| x)
+            .filter(|x| x.len() <= 8);
+        let c = entity.and_then(|x| match x {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            x if x.starts_with("#x") => u32::from_str_radix(&x[2..], 16).ok().and_then(char::from_u32),
+            x if x.starts_with('#') => x[1..].parse().ok().and_then(char::from_u32),
+            _ => None,
+        });
+        match (entity, c) {
+            (Some(entity), Some(c)) => {
+                decoded.push(c);
+                rest = &rest[entity.len() + 2..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+// Number of characters that take up space on the terminal, escape sequences don't
+fn visible_len(word: &str) -> usize {
+    let mut len = 0;
+    let mut escape = false;
+    for c in word.chars() {
+        match c {
+            '\x1b' => escape = true,
+            'm' if escape => escape = false,
+            _ if escape => {}
+            _ => len += 1,
+        }
+    }
+    len
+}
+
+fn wrap(text: &str, width: usize, first_prefix: &str, prefix: &str) -> String {
+    let mut lines: Vec<String> = Vec::new();
+    let mut line = String::from(first_prefix);
+    let mut len = visible_len(first_prefix);
+    for word in text.split(' ').filter(|x| !x.is_empty()) {
+        if len > visible_len(prefix) && len + 1 + visible_len(word) > width {
+            lines.push(line);
+            line = String::from(prefix);
+            len = visible_len(prefix);
+        }
+        if len > visible_len(prefix) {
+            line.push(' ');
+            len += 1;
+        }
+        line.push_str(word);
+        len += visible_len(word);
+    }
+    lines.push(line);
+    lines.join("\n")
+}
+
+struct Renderer {
+    ansi: bool,
+    width: usize,
+    out: Vec<String>,
+    // Text of the current paragraph, heading or list item
+    text: String,
+    // Nesting of the elements that change how text is rendered
+    pre: usize,
+    skip: usize,
+    lists: usize,
+    // Whether the last block is a list item, the items of a list are not separated by blank lines
+    item: bool,
+    // Escape sequences of the open 'em' and 'code' elements, innermost last
+    styles: Vec<&'static str>,
+}
+
+impl Renderer {
+    fn style(&mut self, ansi: &str, plain: &str) {
+        self.text.push_str(if self.ansi { ansi } else { plain });
+    }
+
+    fn push_style(&mut self, ansi: &'static str, plain: &str) {
+        self.styles.push(ansi);
+        self.style(ansi, plain);
+    }
+
+    // A reset clears every style, so the styles of the enclosing elements are applied again
+    fn pop_style(&mut self, plain: &str) {
+        self.styles.pop();
+        let restore = format!("{}{}", RESET, self.styles.concat());
+        self.style(&restore, plain);
+    }
+
+    fn flush(&mut self, first_prefix: &str, prefix: &str) {
+        let text = std::mem::take(&mut self.text);
+        if text.trim().is_empty() {
+            return;
+        }
+        let block = if self.pre > 0 {
+            let lines: Vec<String> = text.trim_matches('\n')
+                .lines()
+                .map(|x| format!("    {}", x).trim_end().to_string())
+                .collect();
+            lines.join("\n")
+        } else {
+            wrap(text.trim(), self.width, first_prefix, prefix)
+        };
+
+        let item = !first_prefix.is_empty();
+        match self.out.last_mut() {
+            Some(last) if item && self.item => {
+                last.push('\n');
+                last.push_str(&block);
+            }
+            _ => self.out.push(block),
+        }
+        self.item = item;
+    }
+
+    fn list_prefixes(&self) -> (String, String) {
+        let indent = "  ".repeat(self.lists.saturating_sub(1));
+        (format!("{}  - ", indent), format!("{}    ", indent))
+    }
+
+    fn open(&mut self, tag: &str) {
+        match tag {
+            "script" | "style" | "head" | "title" => self.skip += 1,
+            _ if self.skip > 0 => {}
+            "h1" | "h2" | "h3" | "p" | "div" => self.flush("", ""),
+            "ul" | "ol" => {
+                self.flush("", "");
+                self.lists += 1;
+            }
+            "li" => {
+                let (first, rest) = self.list_prefixes();
+                self.flush(&first, &rest);
+            }
+            "pre" => {
+                self.flush("", "");
+                self.pre += 1;
+            }
+            "br" => self.text.push('\n'),
+            "em" => self.push_style(HIGHLIGHT, "*"),
+            "code" if self.pre == 0 => self.push_style(CODE, "`"),
+            _ => {}
+        }
+    }
+
+    fn close(&mut self, tag: &str) {
+        match tag {
+            "script" | "style" | "head" | "title" => self.skip = self.skip.saturating_sub(1),
+            _ if self.skip > 0 => {}
+            "h1" | "h2" | "h3" => {
+                let heading = std::mem::take(&mut self.text);
+                self.text = match self.ansi {
+                    true => format!("{}{}{}", BOLD, heading.trim(), RESET),
+                    false => heading,
+                };
+                self.flush("", "");
+            }
+            "p" | "div" => self.flush("", ""),
+            "li" => {
+                let (first, rest) = self.list_prefixes();
+                self.flush(&first, &rest);
+            }
+            "ul" | "ol" => {
+                self.flush("", "");
+                self.lists = self.lists.saturating_sub(1);
+            }
+            "pre" => {
+                self.flush("", "");
+                self.pre = self.pre.saturating_sub(1);
+            }
+            "em" => self.pop_style("*"),
+            "code" if self.pre == 0 => self.pop_style("`"),
+            _ => {}
+        }
+    }
+
+    fn text(&mut self, text: &str) {
+        if self.skip > 0 {
+            return;
+        }
+        let text = decode_entities(text);
+        if self.pre > 0 {
+            self.text.push_str(&text);
+        } else {
+            // Whitespace collapses like in a browser
+            let collapsed: Vec<&str> = text.split_whitespace().collect();
+            if text.starts_with(char::is_whitespace) && !self.text.ends_with(' ') {
+                self.text.push(' ');
+            }
+            self.text.push_str(&collapsed.join(" "));
+            if text.ends_with(char::is_whitespace) && !collapsed.is_empty() {
+                self.text.push(' ');
+            }
+        }
+    }
+}
+
+// A saved puzzle page renders only its '<article>' elements, which hold the puzzle descriptions.
+// Escape sequences for bold and colored text are used if 'ansi' is set, otherwise markdown like markers.
+pub fn render(html: &str, width: usize, ansi: bool) -> String {
+    let mut renderer = Renderer { ansi, width, out: Vec::new(), text: String::new(), pre: 0, skip: 0, lists: 0, item: false, styles: Vec::new() };
+    let articles = html.contains("<article");
+    let mut article: usize = 0;
+    for token in tokenize(html) {
+        match token {
+            Token::Open(tag) if tag == "article" => article += 1,
+            Token::Close(tag) if tag == "article" => {
+                renderer.flush("", "");
+                article = article.saturating_sub(1);
+            }
+            _ if articles && article == 0 => {}
+            Token::Open(tag) => renderer.open(&tag),
+            Token::Close(tag) => renderer.close(&tag),
+            Token::Text(text) => renderer.text(text),
+        }
+    }
+
+    renderer.flush("", "");
+    renderer.out.join("\n\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entities() {
+        assert_eq!(decode_entities("a &amp; b &lt;c&gt; &quot;&#39;&#x41;"), "a & b <c> \"'A");
+        assert_eq!(decode_entities("&bogus; & &amp"), "&bogus; & &amp");
+    }
+
+    #[test]
+    fn nested_styles() {
+        let html = "<p><code>a<em>b</em>c</code> d</p>";
+        assert_eq!(render(html, 80, false), "`a*b*c` d");
+        assert_eq!(render(html, 80, true), format!("{}a{}b{}{}c{} d", CODE, HIGHLIGHT, RESET, CODE, RESET));
+    }
+
+    #[test]
+    fn list_wrapping() {
+        let html = "<ul><li>one two three four</li><li>five</li></ul><p>after</p>";
+        assert_eq!(render(html, 12, false), "  - one two\n    three\n    four\n  - five\n\nafter");
+    }
+
+    #[test]
+    fn articles_only() {
+        let html = "<html><head><title>Day 1</title></head><body><p>menu</p><article><h2>--- Day 1 ---</h2><p>text</p></article></body></html>";
+        assert_eq!(render(html, 80, false), "--- Day 1 ---\n\ntext");
+    }
+}
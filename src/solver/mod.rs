@@ -0,0 +1,200 @@
+use crate::error::{Diagnostics, Error};
+use std::fmt;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Number(x) => write!(f, "{}", x),
+            Answer::Text(x) => write!(f, "{}", x),
+        }
+    }
+}
+
+impl FromStr for Answer {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Answer, String> {
+        match s.parse::<i64>() {
+            Ok(x) => Ok(Answer::Number(x)),
+            Err(_) => Ok(Answer::Text(s.into())),
+        }
+    }
+}
+
+macro_rules! answer_from_number {
+    ($($t:ty),+) => {
+        $(impl From<$t> for Answer {
+            fn from(v: $t) -> Answer {
+                Answer::Number(v as i64)
+            }
+        })+
+    };
+}
+
+answer_from_number!(u8, u16, u32, u64, usize, i8, i16, i32, i64);
+
+impl From<String> for Answer {
+    fn from(v: String) -> Answer {
+        Answer::Text(v)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(v: &str) -> Answer {
+        Answer::Text(v.into())
+    }
+}
+
+pub trait Solver {
+    type Input;
+
+    // Malformed lines should be reported to 'diag', which decides whether to skip them
+    fn parse(input: &str, diag: &mut Diagnostics) -> Result<Self::Input, Error>;
+    fn part_one(input: &Self::Input) -> Result<Answer, Error>;
+    fn part_two(input: &Self::Input) -> Result<Answer, Error>;
+
+    // A picture of how a part is solved, e.g. a map of the visited positions, printed with '--render'.
+    // 'part' is either 'Part::One' or 'Part::Two', None if the day has nothing to show.
+    fn render(_input: &Self::Input, _part: Part) -> Result<Option<String>, Error> {
+        Ok(None)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+    Both,
+}
+
+impl Part {
+    pub fn includes_one(self) -> bool {
+        self != Part::Two
+    }
+
+    pub fn includes_two(self) -> bool {
+        self != Part::One
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Part, String> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            "both" => Ok(Part::Both),
+            _ => Err(format!("expected 1, 2 or both, got '{}'", s))
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    pub part_one: Option<Answer>,
+    pub part_two: Option<Answer>,
+}
+
+impl Answers {
+    // The parts for which an answer is present
+    pub fn part(&self) -> Option<Part> {
+        match (&self.part_one, &self.part_two) {
+            (Some(_), Some(_)) => Some(Part::Both),
+            (Some(_), None) => Some(Part::One),
+            (None, Some(_)) => Some(Part::Two),
+            (None, None) => None,
+        }
+    }
+}
+
+// Answers are stored one per line as '<part>: <answer>', e.g. '1: 24000'
+impl FromStr for Answers {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Answers, String> {
+        let mut answers = Answers::default();
+        for line in s.lines().map(|line| line.trim()).filter(|line| !line.is_empty()) {
+            let (part, answer) = line.split_once(':')
+                .ok_or(format!("expected '<part>: <answer>', got '{}'", line))?;
+            let answer = Some(answer.trim().parse()?);
+            match part.trim() {
+                "1" => answers.part_one = answer,
+                "2" => answers.part_two = answer,
+                part => return Err(format!("expected part 1 or 2, got '{}'", part))
+            }
+        }
+        Ok(answers)
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(x) = &self.part_one {
+            writeln!(f, "1: {}", x)?;
+        }
+        if let Some(x) = &self.part_two {
+            writeln!(f, "2: {}", x)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part_one: Option<Duration>,
+    pub part_two: Option<Duration>,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part_one.unwrap_or_default() + self.part_two.unwrap_or_default()
+    }
+}
+
+pub type SolveFn = fn(&str, Part, &mut Diagnostics) -> Result<(Answers, Timings), Error>;
+
+pub fn solve<S: Solver>(input: &str, part: Part, diag: &mut Diagnostics) -> Result<(Answers, Timings), Error> {
+    let mut answers = Answers::default();
+    let mut timings = Timings::default();
+
+    let now = Instant::now();
+    let input = S::parse(input, diag)?;
+    timings.parse = now.elapsed();
+
+    if part.includes_one() {
+        let now = Instant::now();
+        answers.part_one = Some(S::part_one(&input)?);
+        timings.part_one = Some(now.elapsed());
+    }
+    if part.includes_two() {
+        let now = Instant::now();
+        answers.part_two = Some(S::part_two(&input)?);
+        timings.part_two = Some(now.elapsed());
+    }
+    Ok((answers, timings))
+}
+
+pub type RenderFn = fn(&str, Part, &mut Diagnostics) -> Result<Vec<(Part, String)>, Error>;
+
+// The renders of the selected parts, empty if the solver renders nothing
+pub fn render<S: Solver>(input: &str, part: Part, diag: &mut Diagnostics) -> Result<Vec<(Part, String)>, Error> {
+    let input = S::parse(input, diag)?;
+    let mut renders = Vec::new();
+    let parts = [(Part::One, part.includes_one()), (Part::Two, part.includes_two())];
+    for (part, _) in parts.into_iter().filter(|(_, selected)| *selected) {
+        if let Some(render) = S::render(&input, part)? {
+            renders.push((part, render));
+        }
+    }
+    Ok(renders)
+}
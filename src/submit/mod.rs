@@ -0,0 +1,237 @@
+use crate::fetch::Client;
+use crate::solver::Answer;
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// The website makes you wait a minute after a wrong answer
+const WRONG_ANSWER_DELAY: u64 = 60;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    // Seconds left to wait before the next answer is accepted
+    RateLimited(u64),
+    // The part was solved before, possibly in a browser
+    AlreadySolved,
+}
+
+impl Verdict {
+    fn is_wrong(self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+
+    // Recognizes the sentences of the website's answer page, e.g. 'That's not the right answer; your answer is too high.'
+    pub fn parse_response(body: &str) -> Option<Verdict> {
+        if body.contains("That's the right answer") {
+            Some(Verdict::Correct)
+        } else if body.contains("your answer is too high") {
+            Some(Verdict::TooHigh)
+        } else if body.contains("your answer is too low") {
+            Some(Verdict::TooLow)
+        } else if body.contains("That's not the right answer") {
+            Some(Verdict::Wrong)
+        } else if body.contains("You gave an answer too recently") {
+            // '... You have 39s left to wait.' or '... You have 1m 39s left to wait.'
+            let wait = body.split_once("You have")
+                .and_then(|(_, x)| x.split_once("left to wait"))
+                .map_or(WRONG_ANSWER_DELAY, |(x, _)| x.split_whitespace()
+                    .filter_map(|x| {
+                        let minutes = x.strip_suffix('m').and_then(|n| n.parse::<u64>().ok()).map(|n| n * 60);
+                        minutes.or_else(|| x.strip_suffix('s').and_then(|n| n.parse().ok()))
+                    })
+                    .sum());
+            Some(Verdict::RateLimited(wait))
+        } else if body.contains("Did you already complete it") {
+            Some(Verdict::AlreadySolved)
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too_high"),
+            Verdict::TooLow => write!(f, "too_low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::RateLimited(wait) => write!(f, "wait:{}", wait),
+            Verdict::AlreadySolved => write!(f, "already_solved"),
+        }
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Verdict, String> {
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "too_high" => Ok(Verdict::TooHigh),
+            "too_low" => Ok(Verdict::TooLow),
+            "wrong" => Ok(Verdict::Wrong),
+            "already_solved" => Ok(Verdict::AlreadySolved),
+            _ => s.strip_prefix("wait:")
+                .and_then(|x| x.parse().ok())
+                .map(Verdict::RateLimited)
+                .ok_or(format!("expected a verdict, got '{}'", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    // Seconds since the unix epoch
+    pub time: u64,
+    pub part: u8,
+    pub verdict: Verdict,
+    pub answer: Answer,
+}
+
+pub fn now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |x| x.as_secs())
+}
+
+// Submissions are stored one per line as '<time> <part> <verdict> <answer>', e.g. '1670000000 1 too_high 24001'
+pub fn load_history(path: &Path) -> Result<Vec<Submission>, Box<dyn std::error::Error>> {
+    if !path.is_file() {
+        return Ok(Vec::new());
+    }
+
+    let mut history = Vec::new();
+    for line in fs::read_to_string(path)?.lines().filter(|x| !x.trim().is_empty()) {
+        let error = || format!("Oops, invalid submission '{}' in {}", line, path.display());
+        let fields: Vec<&str> = line.splitn(4, ' ').collect();
+        let [time, part, verdict, answer] = fields[..] else {
+            return Err(error().into());
+        };
+        history.push(Submission {
+            time: time.parse().map_err(|_| error())?,
+            part: part.parse().map_err(|_| error())?,
+            verdict: verdict.parse().map_err(|_| error())?,
+            answer: answer.parse()?,
+        });
+    }
+    Ok(history)
+}
+
+pub fn append_history(path: &Path, submission: &Submission) -> Result<(), Box<dyn std::error::Error>> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{} {} {} {}", submission.time, submission.part, submission.verdict, submission.answer)?;
+    Ok(())
+}
+
+// Refuses answers the history already knows the verdict of and submissions the website would reject
+// for being too soon. Returns warnings for answers outside the known too high and too low bounds.
+pub fn precheck(history: &[Submission], part: u8, answer: &Answer, now: u64) -> Result<Vec<String>, String> {
+    // The website throttles answers of both parts alike
+    let wait_until = history.iter()
+        .filter_map(|x| match x.verdict {
+            Verdict::RateLimited(wait) => Some(x.time + wait),
+            verdict if verdict.is_wrong() => Some(x.time + WRONG_ANSWER_DELAY),
+            _ => None,
+        })
+        .max();
+
+    let history: Vec<&Submission> = history.iter().filter(|x| x.part == part).collect();
+    if let Some(x) = history.iter().find(|x| x.verdict == Verdict::Correct) {
+        return Err(format!("Oops, part {} was already solved with {}", part, x.answer));
+    }
+    if let Some(x) = history.iter().find(|x| x.verdict.is_wrong() && x.answer == *answer) {
+        return Err(format!("Oops, {} was already submitted for part {} and was {}", answer, part, x.verdict.to_string().replace('_', " ")));
+    }
+
+    if let Some(wait_until) = wait_until.filter(|x| *x > now) {
+        return Err(format!("Oops, the website is not accepting answers yet, wait another {}s", wait_until - now));
+    }
+
+    let mut warnings = Vec::new();
+    if let Answer::Number(answer) = answer {
+        let bound = |verdict: Verdict| history.iter()
+            .filter(move |x| x.verdict == verdict)
+            .filter_map(|x| match x.answer {
+                Answer::Number(x) => Some(x),
+                Answer::Text(_) => None,
+            });
+        if let Some(high) = bound(Verdict::TooHigh).min().filter(|x| answer >= x) {
+            warnings.push(format!("{} is not below {}, which was too high", answer, high));
+        }
+        if let Some(low) = bound(Verdict::TooLow).max().filter(|x| answer <= x) {
+            warnings.push(format!("{} is not above {}, which was too low", answer, low));
+        }
+    }
+    Ok(warnings)
+}
+
+pub fn submit(client: &Client, year: u16, day: u8, part: u8, answer: &Answer) -> Result<Verdict, Box<dyn std::error::Error>> {
+    let url = client.url(year, day, "/answer");
+    let body = client.post(&url, &[("level", &part.to_string()), ("answer", &answer.to_string())])?;
+    Verdict::parse_response(&body)
+        .ok_or(format!("Oops, unable to make sense of the answer page of {}", url).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn submission(time: u64, part: u8, verdict: Verdict, answer: i64) -> Submission {
+        Submission { time, part, verdict, answer: Answer::Number(answer) }
+    }
+
+    #[test]
+    fn parse_response_verdicts() {
+        assert_eq!(Verdict::parse_response("<p>That's the right answer! You are one gold star closer.</p>"), Some(Verdict::Correct));
+        assert_eq!(Verdict::parse_response("That's not the right answer; your answer is too high."), Some(Verdict::TooHigh));
+        assert_eq!(Verdict::parse_response("That's not the right answer; your answer is too low."), Some(Verdict::TooLow));
+        assert_eq!(Verdict::parse_response("That's not the right answer. If you're stuck, ..."), Some(Verdict::Wrong));
+        assert_eq!(Verdict::parse_response("You don't seem to be solving the right level. Did you already complete it?"), Some(Verdict::AlreadySolved));
+        assert_eq!(Verdict::parse_response("<html>404</html>"), None);
+    }
+
+    #[test]
+    fn parse_response_wait() {
+        let wait = |body: &str| Verdict::parse_response(&format!("You gave an answer too recently. {}", body));
+        assert_eq!(wait("You have 1m 39s left to wait."), Some(Verdict::RateLimited(99)));
+        assert_eq!(wait("You have 39s left to wait."), Some(Verdict::RateLimited(39)));
+        assert_eq!(wait(""), Some(Verdict::RateLimited(WRONG_ANSWER_DELAY)));
+    }
+
+    #[test]
+    fn verdict_round_trip() {
+        for verdict in [Verdict::Correct, Verdict::TooHigh, Verdict::TooLow, Verdict::Wrong, Verdict::RateLimited(99), Verdict::AlreadySolved] {
+            assert_eq!(verdict.to_string().parse::<Verdict>(), Ok(verdict));
+        }
+    }
+
+    #[test]
+    fn precheck_bounds() {
+        let history = [submission(0, 1, Verdict::TooHigh, 100), submission(0, 1, Verdict::TooLow, 10)];
+        assert_eq!(precheck(&history, 1, &Answer::Number(50), 1000), Ok(Vec::new()));
+        assert_eq!(precheck(&history, 1, &Answer::Number(120), 1000), Ok(vec![String::from("120 is not below 100, which was too high")]));
+        assert_eq!(precheck(&history, 1, &Answer::Number(5), 1000), Ok(vec![String::from("5 is not above 10, which was too low")]));
+        // Bounds of the other part don't apply
+        assert_eq!(precheck(&history, 2, &Answer::Number(120), 1000), Ok(Vec::new()));
+    }
+
+    #[test]
+    fn precheck_refusals() {
+        let history = [submission(1000, 1, Verdict::TooHigh, 100)];
+        assert!(precheck(&history, 1, &Answer::Number(100), 2000).is_err());
+        assert!(precheck(&history, 2, &Answer::Number(7), 1000 + WRONG_ANSWER_DELAY - 1).is_err());
+        assert!(precheck(&history, 2, &Answer::Number(7), 1000 + WRONG_ANSWER_DELAY).is_ok());
+
+        let history = [submission(0, 1, Verdict::Correct, 42)];
+        assert!(precheck(&history, 1, &Answer::Number(43), 1000).is_err());
+    }
+}
@@ -1,19 +1,22 @@
-use crate::utils;
-use std::io::BufRead;
+use crate::aoc;
+use crate::error::{Diagnostics, Error};
+use crate::solver::{Answer, Solver};
 
-pub fn solve(files_dir: String) -> Result<(), Box<dyn std::error::Error>> {
-    part_one(files_dir.clone())?;
-    part_two(files_dir.clone())?;
-    Ok(())
-}
+#[aoc(day = 0)]
+pub struct Solution;
 
-fn part_one(files_dir: String) -> Result<(), Box<dyn std::error::Error>> {
-    let reader = utils::input_file_reader(files_dir)?;
+impl Solver for Solution {
+    type Input = Vec<String>;
 
-    Ok(())
-}
-fn part_two(files_dir: String) -> Result<(), Box<dyn std::error::Error>> {
-    let reader = utils::input_file_reader(files_dir)?;
+    fn parse(input: &str, _diag: &mut Diagnostics) -> Result<Vec<String>, Error> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part_one(_lines: &Vec<String>) -> Result<Answer, Error> {
+        Ok(Answer::Number(0))
+    }
 
-    Ok(())
+    fn part_two(_lines: &Vec<String>) -> Result<Answer, Error> {
+        Ok(Answer::Number(0))
+    }
 }
@@ -0,0 +1,108 @@
+use crate::error::Error;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+// A rectangular grid of cells indexed by '(x, y)', with 'x' the column and 'y' the row from the top
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn filled(width: usize, height: usize, value: T) -> Grid<T>
+    where
+        T: Clone
+    {
+        Grid { width, height, cells: vec![value; width * height] }
+    }
+
+    // Parses one row per line and one cell per character. A grid has no lines to skip, so an unexpected
+    // character or a row that is shorter or longer than the first row is an error even in lenient mode.
+    pub fn parse(str: &str, expected: &str, cell: impl Fn(char) -> Option<T>) -> Result<Grid<T>, Error> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for (i, line) in str.lines().enumerate() {
+            let mut row = 0;
+            for (column, c) in line.chars().enumerate() {
+                cells.push(cell(c).ok_or_else(|| Error::parse(i + 1, line, expected).at_column(column + 1))?);
+                row += 1;
+            }
+            match width {
+                None => width = Some(row),
+                Some(width) if width != row => {
+                    let expected = format!("a row of {} cells like the first row", width);
+                    return Err(Error::parse(i + 1, line, &expected).at_column(row.min(width) + 1));
+                }
+                _ => {}
+            }
+            height += 1;
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Grid { width, height, cells }),
+            _ => Err(Error::parse(1, str, &format!("a grid of {}", expected))),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        (x < self.width && y < self.height).then(|| &self.cells[y * self.width + x])
+    }
+
+    // Positions row by row, from the top left to the bottom right
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0 .. self.height).flat_map(move |y| (0 .. width).map(move |x| (x, y)))
+    }
+
+    // Positions of the cells above, below, left and right of '(x, y)' that are inside the grid
+    pub fn neighbours(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
+        let (width, height) = (self.width, self.height);
+        [(0, -1), (0, 1), (-1, 0), (1, 0)].into_iter()
+            .filter_map(move |(dx, dy)| Some((x.checked_add_signed(dx)?, y.checked_add_signed(dy)?)))
+            .filter(move |(x, y)| *x < width && *y < height)
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(x < self.width && y < self.height, "({}, {}) is outside the {}x{} grid", x, y, self.width, self.height);
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        assert!(x < self.width && y < self.height, "({}, {}) is outside the {}x{} grid", x, y, self.width, self.height);
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+// One line per row, without separators between the cells
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.cells.chunks(self.width) {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
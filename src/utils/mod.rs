@@ -1,7 +1,93 @@
+use crate::error::Error;
 use std::fs::File;
-use std::io::BufReader;
+use std::io::{self, Read};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
 
-pub fn input_file_reader(filepath: String) -> Result<BufReader<File>, Box<dyn std::error::Error>> {
-    let file = File::open(filepath + "/input")?;
-    Ok(BufReader::new(file))
+mod grid;
+mod point;
+
+pub use grid::Grid;
+pub use point::Point;
+
+// Falls back to the 'files' directory of this crate so the binary can be run from anywhere
+pub fn data_dir(data_dir: Option<PathBuf>) -> PathBuf {
+    data_dir.unwrap_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join("files"))
+}
+
+// Reads from stdin if the path is '-'
+pub fn read_input(path: &Path) -> Result<String, Error> {
+    let mut input = String::new();
+    let result = if path == Path::new("-") {
+        io::stdin().read_to_string(&mut input)
+    } else {
+        File::open(path).and_then(|mut file| file.read_to_string(&mut input))
+    };
+
+    result.map_err(|e| Error::from(e).in_file(path))?;
+    Ok(input)
+}
+
+pub fn print_table(header: &[&str], rows: &[Vec<String>]) {
+    let mut widths: Vec<usize> = header.iter().map(|x| x.len()).collect();
+    rows.iter().for_each(|row| {
+        row.iter()
+            .enumerate()
+            .for_each(|(i, x)| widths[i] = widths[i].max(x.chars().count()));
+    });
+
+    let format_row = |row: Vec<&str>| -> String {
+        row.iter()
+            .zip(widths.iter())
+            .map(|(x, width)| format!("{:<width$}", x, width = width))
+            .collect::<Vec<_>>()
+            .join(" | ")
+            .trim_end()
+            .to_string()
+    };
+
+    println!("{}", format_row(header.to_vec()));
+    println!("{}", widths.iter().map(|x| "-".repeat(*x)).collect::<Vec<_>>().join("-|-"));
+    rows.iter().for_each(|row| println!("{}", format_row(row.iter().map(|x| x.as_str()).collect())));
+}
+
+// Number of worker threads when none is given, one per CPU core
+pub fn default_jobs() -> usize {
+    thread::available_parallelism().map_or(1, |x| x.get())
+}
+
+// Maps every item on at most 'jobs' threads and returns the results in the order of the items,
+// a panic only fails its own item with the panic message
+pub fn parallel_map<T: Sync, R: Send>(items: &[T], jobs: usize, f: impl Fn(&T) -> R + Sync) -> Vec<Result<R, String>> {
+    let next = AtomicUsize::new(0);
+    let results: Mutex<Vec<Option<Result<R, String>>>> = Mutex::new(items.iter().map(|_| None).collect());
+
+    thread::scope(|scope| {
+        for _ in 0 .. jobs.clamp(1, items.len().max(1)) {
+            scope.spawn(|| loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(i) else {
+                    break;
+                };
+
+                let result = panic::catch_unwind(AssertUnwindSafe(|| f(item)))
+                    .map_err(|e| {
+                        let message = e.downcast_ref::<&str>().map(|x| x.to_string())
+                            .or_else(|| e.downcast_ref::<String>().cloned())
+                            .unwrap_or_default();
+                        format!("panicked: {}", message)
+                    });
+                results.lock().unwrap()[i] = Some(result);
+            });
+        }
+    });
+
+    results.into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|x| x.unwrap())
+        .collect()
 }
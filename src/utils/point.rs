@@ -0,0 +1,62 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Sub};
+
+// A position or offset on an unbounded 2D plane, 'y' grows downwards like the rows of a grid
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const UP: Point = Point { x: 0, y: -1 };
+    pub const DOWN: Point = Point { x: 0, y: 1 };
+    pub const LEFT: Point = Point { x: -1, y: 0 };
+    pub const RIGHT: Point = Point { x: 1, y: 0 };
+
+    pub fn new(x: i64, y: i64) -> Point {
+        Point { x, y }
+    }
+
+    // An offset of at most one step along each axis in the same direction
+    pub fn signum(self) -> Point {
+        Point::new(self.x.signum(), self.y.signum())
+    }
+
+    pub fn manhattan(self, other: Point) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    // Number of king moves between the points, diagonal neighbours are at distance 1
+    pub fn chebyshev(self, other: Point) -> i64 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
@@ -0,0 +1,99 @@
+use crate::solver::Answers;
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::SystemTime;
+
+// Answers of the last run before a restart, so the rebuilt binary can keep diffing against them
+const PREVIOUS_ANSWERS: &str = "AOC_WATCH_PREVIOUS";
+
+// Modification time of every file below the watched paths
+pub type Snapshot = HashMap<PathBuf, SystemTime>;
+
+// Paths that do not exist (yet) are skipped
+pub fn snapshot(paths: &[PathBuf]) -> Snapshot {
+    let mut snapshot = Snapshot::new();
+    let mut stack: Vec<PathBuf> = paths.to_vec();
+    while let Some(path) = stack.pop() {
+        let Ok(metadata) = fs::metadata(&path) else {
+            continue;
+        };
+
+        if metadata.is_dir() {
+            if let Ok(entries) = fs::read_dir(&path) {
+                stack.extend(entries.flatten().map(|x| x.path()));
+            }
+        } else if let Ok(modified) = metadata.modified() {
+            snapshot.insert(path, modified);
+        }
+    }
+    snapshot
+}
+
+// Files that were added, modified or removed between two snapshots
+pub fn changes(old: &Snapshot, new: &Snapshot) -> Vec<PathBuf> {
+    let mut changes: Vec<PathBuf> = new.iter()
+        .filter(|(path, modified)| old.get(*path) != Some(*modified))
+        .map(|(path, _)| path.clone())
+        .chain(old.keys().filter(|path| !new.contains_key(*path)).cloned())
+        .collect();
+    changes.sort();
+    changes
+}
+
+// One line per answered part, e.g. '1: 24000 -> 24001' when the answer changed since the previous run
+pub fn diff(previous: Option<&Answers>, answers: &Answers) -> Vec<String> {
+    let before = |part: u8| previous.map(|x| if part == 1 { &x.part_one } else { &x.part_two });
+    [(1, &answers.part_one), (2, &answers.part_two)]
+        .into_iter()
+        .filter_map(|(part, after)| {
+            let after = after.as_ref()?;
+            let line = match before(part) {
+                None => format!("{}: {}", part, after),
+                Some(Some(before)) if before == after => format!("{}: {} (unchanged)", part, after),
+                Some(Some(before)) => format!("{}: {} -> {}", part, before, after),
+                Some(None) => format!("{}: {} (new)", part, after),
+            };
+            Some(line)
+        })
+        .collect()
+}
+
+// Answers handed over by the process that restarted this one, see restart
+pub fn previous() -> Option<Answers> {
+    env::var(PREVIOUS_ANSWERS).ok()?.parse().ok()
+}
+
+// Builds the crate in 'root' with the profile of the running binary, cargo reports the compile errors itself
+pub fn rebuild(root: &Path) -> Result<bool, Box<dyn std::error::Error>> {
+    let mut cargo = Command::new(env::var_os("CARGO").unwrap_or("cargo".into()));
+    cargo.arg("build").current_dir(root);
+    if !cfg!(debug_assertions) {
+        cargo.arg("--release");
+    }
+    Ok(cargo.status()?.success())
+}
+
+// Replaces the running process by 'exe' with the same arguments, only returns when that failed
+pub fn restart(exe: &Path, previous: Option<&Answers>) -> Result<(), Box<dyn std::error::Error>> {
+    let mut command = Command::new(exe);
+    command.args(env::args_os().skip(1));
+    match previous {
+        Some(answers) => command.env(PREVIOUS_ANSWERS, answers.to_string()),
+        None => command.env_remove(PREVIOUS_ANSWERS),
+    };
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        Err(command.exec().into())
+    }
+
+    #[cfg(not(unix))]
+    {
+        let status = command.status()?;
+        std::process::exit(status.code().unwrap_or(1));
+    }
+}
@@ -0,0 +1,86 @@
+use crate::aoc;
+use crate::error::{Diagnostics, Error};
+use crate::solver::{Answer, Solver};
+use std::ops::RangeInclusive;
+
+pub trait RangeExt {
+    fn contains(&self, other: &Self) -> bool;
+    fn intersects(&self, other: &Self) -> bool;
+}
+
+impl RangeExt for RangeInclusive<u8> {
+    fn contains(&self, other: &Self) -> bool {
+        other.contains(self.start()) &&
+        other.contains(self.end())
+    }
+
+    fn intersects(&self, other: &Self) -> bool {
+        other.contains(self.start()) ||
+        other.contains(self.end())
+    }
+}
+
+pub fn overlaps<T>(x: &T, y: &T) -> bool
+where
+    T: RangeExt
+{
+    x.contains(y) || y.contains(x)
+}
+
+pub fn intersects<T>(x: &T, y: &T) -> bool
+where
+    T: RangeExt
+{
+    x.intersects(y) || y.contains(x)
+}
+
+pub fn parse_range(pair: &str) -> Option<RangeInclusive<u8>> {
+    let (x, y) = pair.split_once('-')?;
+    let x: u8 = x.parse().ok()?;
+    let y: u8 = y.parse().ok()?;
+    if x > y {
+        return None;
+    }
+    Some(x ..= y)
+}
+
+fn parse_pair(i: usize, line: &str) -> Result<(RangeInclusive<u8>, RangeInclusive<u8>), Error> {
+    let expected = "'<start>-<end>,<start>-<end>'";
+    let (x, y) = line.split_once(',')
+        .ok_or(Error::parse(i + 1, line, expected))?;
+    let x_range = parse_range(x)
+        .ok_or(Error::parse(i + 1, x, expected).at_column(1))?;
+    let y_range = parse_range(y)
+        .ok_or(Error::parse(i + 1, y, expected).at_column(x.len() + 2))?;
+    Ok((x_range, y_range))
+}
+
+#[aoc(day = 4, title = "Camp Cleanup")]
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Vec<(RangeInclusive<u8>, RangeInclusive<u8>)>;
+
+    fn parse(input: &str, diag: &mut Diagnostics) -> Result<Self::Input, Error> {
+        input.lines()
+            .enumerate()
+            .filter_map(|(i, line)| diag.report(parse_pair(i, line)).transpose())
+            .collect()
+    }
+
+    fn part_one(pairs: &Self::Input) -> Result<Answer, Error> {
+        let count: u32 = pairs.iter()
+            .map(|(x, y)| overlaps(x, y) as u32)
+            .sum();
+
+        Ok(count.into())
+    }
+
+    fn part_two(pairs: &Self::Input) -> Result<Answer, Error> {
+        let count: u32 = pairs.iter()
+            .map(|(x, y)| intersects(x, y) as u32)
+            .sum();
+
+        Ok(count.into())
+    }
+}
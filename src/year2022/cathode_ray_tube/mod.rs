@@ -0,0 +1,151 @@
+use crate::aoc;
+use crate::error::{Diagnostics, Error};
+use crate::solver::{Answer, Part, Solver};
+use crate::utils::Grid;
+
+const CRT_WIDTH: usize = 40;
+const CRT_HEIGHT: usize = 6;
+const SAMPLED_CYCLES: [usize; 6] = [20, 60, 100, 140, 180, 220];
+
+// The block letters the CRT draws, 4 pixels wide and 6 high with a blank column between letters
+const LETTER_WIDTH: usize = 5;
+const FONT: [(char, [&str; CRT_HEIGHT]); 16] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Noop,
+    Addx(i64),
+}
+
+impl Instruction {
+    fn cycles(self) -> usize {
+        match self {
+            Instruction::Noop => 1,
+            Instruction::Addx(_) => 2,
+        }
+    }
+}
+
+fn parse_instruction(i: usize, line: &str) -> Result<Instruction, Error> {
+    let expected = "'noop' or 'addx <value>'";
+    match line.split_once(' ') {
+        None if line == "noop" => Ok(Instruction::Noop),
+        Some(("addx", value)) => value.parse()
+            .map(Instruction::Addx)
+            .map_err(|_| Error::parse(i + 1, line, expected).at_column(6)),
+        _ => Err(Error::parse(i + 1, line, expected)),
+    }
+}
+
+// Runs a program one clock cycle at a time, yielding the number of every cycle, starting at 1,
+// with the value of the X register during that cycle. An instruction takes effect after its last cycle.
+pub struct Cpu<'a> {
+    program: &'a [Instruction],
+    pc: usize,
+    // Cycles spent on the current instruction
+    busy: usize,
+    cycle: usize,
+    x: i64,
+}
+
+impl Cpu<'_> {
+    pub fn new(program: &[Instruction]) -> Cpu<'_> {
+        Cpu { program, pc: 0, busy: 0, cycle: 0, x: 1 }
+    }
+}
+
+impl Iterator for Cpu<'_> {
+    type Item = (usize, i64);
+
+    fn next(&mut self) -> Option<(usize, i64)> {
+        let instruction = *self.program.get(self.pc)?;
+        self.cycle += 1;
+        let during = (self.cycle, self.x);
+
+        self.busy += 1;
+        if self.busy == instruction.cycles() {
+            if let Instruction::Addx(value) = instruction {
+                self.x += value;
+            }
+            self.pc += 1;
+            self.busy = 0;
+        }
+        Some(during)
+    }
+}
+
+// The CRT draws a pixel per cycle, row by row, which is lit if the 3 pixels wide sprite centered
+// at X covers it. Pixels after the end of the program stay dark.
+pub fn draw(program: &[Instruction]) -> Grid<bool> {
+    let mut crt = Grid::filled(CRT_WIDTH, CRT_HEIGHT, false);
+    for (cycle, x) in Cpu::new(program).take(CRT_WIDTH * CRT_HEIGHT) {
+        let pixel = cycle - 1;
+        let column = pixel % CRT_WIDTH;
+        crt[(column, pixel / CRT_WIDTH)] = (x - column as i64).abs() <= 1;
+    }
+    crt
+}
+
+fn picture(crt: &Grid<bool>) -> String {
+    crt.map(|x| if *x { '#' } else { '.' }).to_string()
+}
+
+pub fn decode(crt: &Grid<bool>) -> Result<String, Error> {
+    (0 .. crt.width() / LETTER_WIDTH)
+        .map(|i| {
+            let pixel = |x: usize, y: usize| if crt[(i * LETTER_WIDTH + x, y)] { '#' } else { '.' };
+            FONT.iter()
+                .find(|(_, rows)| rows.iter().enumerate().all(|(y, row)| row.chars().enumerate().all(|(x, c)| pixel(x, y) == c)))
+                .map(|(letter, _)| *letter)
+                .ok_or_else(|| Error::solve(&format!("Oops, unable to decode letter {} of the CRT, see the picture with --render", i + 1)))
+        })
+        .collect()
+}
+
+#[aoc(day = 10, title = "Cathode-Ray Tube")]
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str, diag: &mut Diagnostics) -> Result<Vec<Instruction>, Error> {
+        input.lines()
+            .enumerate()
+            .filter_map(|(i, line)| diag.report(parse_instruction(i, line)).transpose())
+            .collect()
+    }
+
+    // Sum of the signal strengths, the cycle number times X, of the sampled cycles
+    fn part_one(program: &Vec<Instruction>) -> Result<Answer, Error> {
+        let strength: i64 = Cpu::new(program)
+            .filter(|(cycle, _)| SAMPLED_CYCLES.contains(cycle))
+            .map(|(cycle, x)| cycle as i64 * x)
+            .sum();
+        Ok(strength.into())
+    }
+
+    fn part_two(program: &Vec<Instruction>) -> Result<Answer, Error> {
+        Ok(decode(&draw(program))?.into())
+    }
+
+    fn render(program: &Vec<Instruction>, part: Part) -> Result<Option<String>, Error> {
+        Ok((part == Part::Two).then(|| picture(&draw(program))))
+    }
+}
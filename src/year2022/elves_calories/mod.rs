@@ -0,0 +1,81 @@
+use crate::aoc;
+use crate::error::{Diagnostics, Error};
+use crate::solver::{Answer, Solver};
+use std::cmp::Ordering;
+
+#[derive(Debug, Default, Clone, Eq)]
+pub struct Elve {
+    calories: i32
+}
+
+impl PartialOrd for Elve {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Elve {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.calories.cmp(&other.calories)
+    }
+}
+
+impl PartialEq for Elve {
+    fn eq(&self, other: &Self) -> bool {
+        self.calories == other.calories
+    }
+}
+
+impl Elve {
+    pub fn add_calories(&mut self, calories: i32) {
+        self.calories += calories;
+    }
+}
+
+#[aoc(day = 1, title = "Calorie Counting")]
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Vec<Elve>;
+
+    fn parse(input: &str, diag: &mut Diagnostics) -> Result<Vec<Elve>, Error> {
+        let mut elves: Vec<Elve> = vec![Elve::default()];
+
+        for (i, line) in input.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() {
+                elves.push(Elve::default());
+            } else {
+                let calories = line.parse()
+                    .map_err(|_| Error::parse(i + 1, line, "a number of calories or an empty line"));
+                if let Some(calories) = diag.report(calories)? {
+                    elves.last_mut()
+                        .unwrap()
+                        .add_calories(calories);
+                }
+            }
+        }
+
+        elves.sort();
+        Ok(elves)
+    }
+
+    fn part_one(elves: &Vec<Elve>) -> Result<Answer, Error> {
+        let answer = elves.last()
+            .map(|x| x.calories)
+            .ok_or(Error::solve("Oops, no elves found"))?;
+
+        Ok(answer.into())
+    }
+
+    fn part_two(elves: &Vec<Elve>) -> Result<Answer, Error> {
+        let sum_of_three: i32 = elves.iter()
+            .rev()
+            .take(3)
+            .map(|x| x.calories)
+            .sum();
+
+        Ok(sum_of_three.into())
+    }
+}
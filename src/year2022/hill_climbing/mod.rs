@@ -0,0 +1,120 @@
+use crate::aoc;
+use crate::error::{Diagnostics, Error};
+use crate::solver::{Answer, Part, Solver};
+use crate::utils::Grid;
+use std::collections::VecDeque;
+
+type Pos = (usize, usize);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeightMap {
+    // 0 for 'a' up to 25 for 'z', the start is at height 'a' and the end at height 'z'
+    heights: Grid<u8>,
+    start: Pos,
+    end: Pos,
+}
+
+// The only position of 'marker' in the map
+fn find(input: &str, map: &Grid<char>, marker: char) -> Result<Pos, Error> {
+    let mut found = map.positions().filter(|x| map[*x] == marker);
+    let pos = found.next()
+        .ok_or_else(|| Error::parse(1, input.lines().next().unwrap_or_default(), &format!("a map with one '{}'", marker)))?;
+    match found.next() {
+        Some((x, y)) => Err(Error::parse(y + 1, input.lines().nth(y).unwrap_or_default(), &format!("a single '{}'", marker)).at_column(x + 1)),
+        None => Ok(pos),
+    }
+}
+
+// The number of steps from every position to the end, None if the end can't be reached, and the
+// position of the next step. Searches backwards from the end, a step can climb at most one up.
+fn steps_to_end(map: &HeightMap) -> (Grid<Option<usize>>, Grid<Option<Pos>>) {
+    let mut steps = map.heights.map(|_| None);
+    let mut next = map.heights.map(|_| None);
+    let mut queue = VecDeque::from([map.end]);
+    steps[map.end] = Some(0);
+    while let Some(to) = queue.pop_front() {
+        let distance = steps[to].unwrap_or_default() + 1;
+        for from in map.heights.neighbours(to.0, to.1) {
+            if steps[from].is_none() && map.heights[to] <= map.heights[from] + 1 {
+                steps[from] = Some(distance);
+                next[from] = Some(to);
+                queue.push_back(from);
+            }
+        }
+    }
+    (steps, next)
+}
+
+// The start of the shortest path of the part, the 'S' or the closest 'a'
+fn start(map: &HeightMap, steps: &Grid<Option<usize>>, part: Part) -> Option<Pos> {
+    match part {
+        Part::One => steps[map.start].map(|_| map.start),
+        _ => map.heights.positions()
+            .filter(|x| map.heights[*x] == 0)
+            .filter(|x| steps[*x].is_some())
+            .min_by_key(|x| steps[*x]),
+    }
+}
+
+fn shortest_path(map: &HeightMap, part: Part) -> Result<usize, Error> {
+    let (steps, _) = steps_to_end(map);
+    let error = match part {
+        Part::One => "Oops, there is no path from S to E",
+        _ => "Oops, there is no path from any 'a' to E",
+    };
+    start(map, &steps, part)
+        .and_then(|x| steps[x])
+        .ok_or(Error::solve(error))
+}
+
+// The path drawn as arrows, e.g. '>>v', over the height letters of the map with its 'S' and 'E'
+fn render_path(map: &HeightMap, part: Part) -> String {
+    let (steps, next) = steps_to_end(map);
+    let mut picture = map.heights.map(|x| (b'a' + x) as char);
+    picture[map.start] = 'S';
+    picture[map.end] = 'E';
+    let mut pos = start(map, &steps, part);
+    while let Some(from) = pos.filter(|x| *x != map.end) {
+        let to = next[from].unwrap_or(map.end);
+        picture[from] = match (to.0 as i64 - from.0 as i64, to.1 as i64 - from.1 as i64) {
+            (1, _) => '>',
+            (-1, _) => '<',
+            (_, 1) => 'v',
+            _ => '^',
+        };
+        pos = next[from];
+    }
+    picture.to_string()
+}
+
+#[aoc(day = 12, title = "Hill Climbing Algorithm")]
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = HeightMap;
+
+    // A map has no lines to skip, see 'Grid::parse'
+    fn parse(input: &str, _diag: &mut Diagnostics) -> Result<HeightMap, Error> {
+        let map = Grid::parse(input, "heights from 'a' to 'z', 'S' or 'E'", |c| matches!(c, 'a' ..= 'z' | 'S' | 'E').then_some(c))?;
+        let start = find(input, &map, 'S')?;
+        let end = find(input, &map, 'E')?;
+        let heights = map.map(|c| match c {
+            'S' => 0,
+            'E' => b'z' - b'a',
+            c => *c as u8 - b'a',
+        });
+        Ok(HeightMap { heights, start, end })
+    }
+
+    fn part_one(map: &HeightMap) -> Result<Answer, Error> {
+        Ok(shortest_path(map, Part::One)?.into())
+    }
+
+    fn part_two(map: &HeightMap) -> Result<Answer, Error> {
+        Ok(shortest_path(map, Part::Two)?.into())
+    }
+
+    fn render(map: &HeightMap, part: Part) -> Result<Option<String>, Error> {
+        Ok(Some(render_path(map, part)))
+    }
+}
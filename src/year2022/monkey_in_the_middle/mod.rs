@@ -0,0 +1,196 @@
+use crate::aoc;
+use crate::error::{Diagnostics, Error};
+use crate::solver::{Answer, Solver};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operand {
+    Old,
+    Value(u64),
+}
+
+// 'new = old <+|*> <old|value>'
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    Add(Operand),
+    Multiply(Operand),
+}
+
+impl Operation {
+    // Worry levels are at most 64 bits, so the result of an operation always fits
+    fn apply(self, old: u64) -> u128 {
+        let value = |x| match x {
+            Operand::Old => old as u128,
+            Operand::Value(x) => x as u128,
+        };
+        match self {
+            Operation::Add(x) => old as u128 + value(x),
+            Operation::Multiply(x) => old as u128 * value(x),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Monkey {
+    items: Vec<u64>,
+    operation: Operation,
+    divisor: u64,
+    // Monkeys to throw to if the worry level is divisible or not
+    targets: (usize, usize),
+}
+
+// A line of a monkey block with the text after its label, e.g. '79, 98' of '  Starting items: 79, 98'
+struct Field<'a> {
+    line: usize,
+    text: &'a str,
+    value: &'a str,
+}
+
+impl Field<'_> {
+    fn error(&self, expected: &str) -> Error {
+        self.error_at(self.value, expected)
+    }
+
+    // 'at' is the part of the value that is wrong, e.g. one of the items
+    fn error_at(&self, at: &str, expected: &str) -> Error {
+        let column = at.as_ptr() as usize - self.text.as_ptr() as usize + 1;
+        Error::parse(self.line, self.text, expected).at_column(column)
+    }
+}
+
+// 'line' is the line number of the first line of the block, 'count' the number of monkeys
+fn parse_monkey(line: usize, block: &str, id: usize, count: usize) -> Result<Monkey, Error> {
+    let mut lines = block.lines().enumerate().map(|(i, text)| (line + i, text));
+    let last = block.lines().enumerate().last().map_or((line, block), |(i, text)| (line + i, text));
+    let mut field = |label: &str, expected: &str| match lines.next() {
+        Some((line, text)) => text.trim_start()
+            .strip_prefix(label)
+            .map(|value| Field { line, text, value })
+            .ok_or(Error::parse(line, text, expected)),
+        None => Err(Error::parse(last.0, last.1, &format!("to be followed by {}", expected))),
+    };
+
+    let header = field("Monkey ", "'Monkey <number>:'")?;
+    if header.value != format!("{}:", id) {
+        return Err(header.error(&format!("'Monkey {}:', the monkeys are numbered from 0 in order", id)));
+    }
+
+    let expected = "'Starting items: <worry>, <worry>, ...'";
+    let items = field("Starting items:", expected)?;
+    let items = items.value.split(',')
+        .map(|x| x.trim())
+        .filter(|x| !x.is_empty())
+        .map(|x| x.parse().map_err(|_| items.error_at(x, expected)))
+        .collect::<Result<Vec<u64>, _>>()?;
+
+    let expected = "'Operation: new = old <+|*> <old|value>'";
+    let operation = field("Operation: new = old ", expected)?;
+    let operand = match operation.value.get(2 ..).map(str::trim) {
+        Some("old") => Some(Operand::Old),
+        Some(x) => x.parse().ok().map(Operand::Value),
+        None => None,
+    };
+    let operation = match (operation.value.get(.. 2), operand) {
+        (Some("+ "), Some(x)) => Operation::Add(x),
+        (Some("* "), Some(x)) => Operation::Multiply(x),
+        _ => return Err(operation.error(expected)),
+    };
+
+    let expected = "'Test: divisible by <number>', with a number above 0";
+    let test = field("Test: divisible by ", expected)?;
+    let divisor = test.value.parse().ok()
+        .filter(|x| *x > 0)
+        .ok_or_else(|| test.error(expected))?;
+
+    let mut target = |label: &str| -> Result<usize, Error> {
+        let expected = format!("'{}<number>', another monkey from 0 to {}", label, count - 1);
+        let target = field(label, &expected)?;
+        target.value.parse().ok()
+            .filter(|x| *x < count && *x != id)
+            .ok_or_else(|| target.error(&expected))
+    };
+    let targets = (target("If true: throw to monkey ")?, target("If false: throw to monkey ")?);
+
+    match lines.next() {
+        Some((line, text)) => Err(Error::parse(line, text, "an empty line between monkeys")),
+        None => Ok(Monkey { items, operation, divisor, targets }),
+    }
+}
+
+// How worry levels are kept manageable after every inspection
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Relief {
+    Divide(u64),
+    // Reducing modulo a multiple of every divisor keeps the outcome of every test the same
+    Modulo(u64),
+}
+
+impl Relief {
+    fn apply(self, worry: u128) -> Result<u64, Error> {
+        let worry = match self {
+            Relief::Divide(x) => worry / x as u128,
+            Relief::Modulo(x) => worry % x as u128,
+        };
+        u64::try_from(worry).map_err(|_| Error::solve("Oops, a worry level no longer fits in 64 bits"))
+    }
+}
+
+fn gcd(x: u64, y: u64) -> u64 {
+    if y == 0 { x } else { gcd(y, x % y) }
+}
+
+fn lcm(x: u64, y: u64) -> Option<u64> {
+    (x / gcd(x, y)).checked_mul(y)
+}
+
+// Product of the number of items inspected by the two most active monkeys
+pub fn monkey_business(monkeys: &[Monkey], rounds: usize, relief: Relief) -> Result<u64, Error> {
+    let mut items: Vec<Vec<u64>> = monkeys.iter().map(|x| x.items.clone()).collect();
+    let mut inspected = vec![0; monkeys.len()];
+    for _ in 0 .. rounds {
+        for (i, monkey) in monkeys.iter().enumerate() {
+            for worry in std::mem::take(&mut items[i]) {
+                inspected[i] += 1;
+                let worry = relief.apply(monkey.operation.apply(worry))?;
+                let target = if worry % monkey.divisor == 0 { monkey.targets.0 } else { monkey.targets.1 };
+                items[target].push(worry);
+            }
+        }
+    }
+
+    inspected.sort_unstable_by(|x, y| y.cmp(x));
+    Ok(inspected.iter().take(2).product())
+}
+
+#[aoc(day = 11, title = "Monkey in the Middle")]
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Vec<Monkey>;
+
+    // A malformed monkey can't be skipped, the other monkeys throw items to it by number
+    fn parse(input: &str, _diag: &mut Diagnostics) -> Result<Vec<Monkey>, Error> {
+        if input.trim().is_empty() {
+            return Err(Error::parse(1, input.trim(), "a list of monkeys, starting with 'Monkey 0:'"));
+        }
+
+        let blocks: Vec<&str> = input.trim_end().split("\n\n").collect();
+        let mut line = 1;
+        let mut monkeys = Vec::new();
+        for (id, block) in blocks.iter().enumerate() {
+            monkeys.push(parse_monkey(line, block, id, blocks.len())?);
+            line += block.lines().count() + 1;
+        }
+        Ok(monkeys)
+    }
+
+    fn part_one(monkeys: &Vec<Monkey>) -> Result<Answer, Error> {
+        Ok(monkey_business(monkeys, 20, Relief::Divide(3))?.into())
+    }
+
+    fn part_two(monkeys: &Vec<Monkey>) -> Result<Answer, Error> {
+        let modulus = monkeys.iter()
+            .try_fold(1, |modulus, x| lcm(modulus, x.divisor))
+            .ok_or(Error::solve("Oops, the least common multiple of the divisors doesn't fit in 64 bits"))?;
+        Ok(monkey_business(monkeys, 10_000, Relief::Modulo(modulus))?.into())
+    }
+}
@@ -0,0 +1,208 @@
+use crate::aoc;
+use crate::error::{Diagnostics, Error};
+use crate::solver::{Answer, Solver};
+use std::rc::Rc;
+use std::cell::RefCell;
+
+#[derive(Debug)]
+pub struct File {
+    size: u32,
+}
+
+impl File {
+    pub fn new(size: u32) -> File {
+        File { size }
+    }
+}
+
+pub type DirectoryRef = Rc<RefCell<Directory>>;
+
+#[derive(Debug)]
+pub struct Directory {
+    name: String,
+    dirs: Vec<DirectoryRef>,
+    files: Vec<File>,
+}
+
+impl Directory {
+    pub fn new(name: &str) -> Directory {
+        Directory {
+            name: name.into(),
+            dirs: Vec::new(),
+            files: Vec::new(),
+        }
+    }
+
+    pub fn into_ref(self) -> DirectoryRef {
+        Rc::new(RefCell::new(self))
+    }
+
+    pub fn get_or_insert_dir(&mut self, dir: Directory) -> DirectoryRef {
+        let x = self.dirs.iter()
+            .find(|x| x.as_ref().borrow().name == dir.name);
+
+        match x {
+            Some(x) => x.clone(),
+            None => {
+                let dir = dir.into_ref();
+                self.dirs.push(dir.clone());
+                dir
+            }
+        }
+    }
+
+    pub fn set_dirs(&mut self, dirs: Vec<Directory>) {
+        self.dirs.clear();
+        dirs.into_iter().for_each(|dir| self.dirs.push(dir.into_ref()));
+    }
+
+    pub fn set_files(&mut self, files: Vec<File>) {
+        self.files = files;
+    }
+
+    pub fn size(&self) -> u32 {
+        let dir_sizes: u32 = self.dirs.iter()
+            .map(|x| x.as_ref().borrow().size())
+            .sum();
+        let file_sizes: u32 = self.files.iter()
+            .map(|x| x.size)
+            .sum();
+
+        dir_sizes + file_sizes
+    }
+
+    pub fn dirs(&self) -> Vec<DirectoryRef> {
+        let mut flattened:Vec<DirectoryRef> = Vec::new();
+        self.dirs.iter().for_each(|dir| {
+            let mut dirs = dir.as_ref().borrow().dirs();
+            flattened.append(&mut dirs);
+            flattened.push(dir.clone());
+        });
+        flattened
+    }
+}
+
+#[derive(Debug)]
+pub enum Command {
+    DirRoot,
+    DirPush(Directory),
+    DirPop,
+    List(Vec<Directory>, Vec<File>),
+}
+
+impl Command {
+    // 'line' is the line number of the command, used for reporting errors
+    pub fn parse(line: usize, str: &str, diag: &mut Diagnostics) -> Result<Command, Error> {
+        let lines: Vec<&str> = str.split('\n').collect();
+        let command: Vec<&str> = lines[0].split(' ').collect();
+        match command[..] {
+            // cd /, cd .., cd <file_name>
+            ["cd", dir] => match dir {
+                "/"  => Ok(Command::DirRoot),
+                ".." => Ok(Command::DirPop),
+                name => Ok(Command::DirPush(Directory::new(name)))
+            },
+            // ls, no dirs or files
+            ["ls"] if lines.len() == 1 => Ok(Command::List(Vec::new(), Vec::new())),
+            // ls, atleast one dir or file
+            ["ls"] => {
+                let mut dirs: Vec<Directory> = Vec::new();
+                let mut files: Vec<File> = Vec::new();
+                for (i, entry) in lines[1..].iter().enumerate() {
+                    let split: Vec<&str> = entry.split(' ').collect();
+                    let error = || Error::parse(line + i + 1, entry, "'dir <name>' or '<size> <name>'");
+                    match split[..] {
+                        ["dir", name] => { dirs.push(Directory::new(name)); }
+                        [size, _name] => {
+                            let size = size.parse::<u32>()
+                                .map_err(|_| error().at_column(1));
+                            if let Some(size) = diag.report(size)? {
+                                files.push(File::new(size));
+                            }
+                        }
+                        _ => { diag.report::<()>(Err(error()))?; }
+                    }
+                }
+                Ok(Command::List(dirs, files))
+            }
+            _ => Err(Error::parse(line, lines[0], "'cd <dir>' or 'ls'"))
+        }
+    }
+}
+
+#[aoc(day = 7, title = "No Space Left On Device")]
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = DirectoryRef;
+
+    fn parse(input: &str, diag: &mut Diagnostics) -> Result<DirectoryRef, Error> {
+        let fs = Directory::new("/").into_ref();
+        let mut stack: Vec<DirectoryRef> = vec![fs.clone()];
+
+        let mut chunks = input.split('$');
+        let mut line = 1;
+        if let Some(text) = chunks.next().filter(|x| !x.trim().is_empty()) {
+            diag.report::<()>(Err(Error::parse(line, text.trim(), "a command starting with '$'")))?;
+            line += text.matches('\n').count();
+        }
+
+        for chunk in chunks {
+            let cmd = Command::parse(line, chunk.trim(), diag);
+            match diag.report(cmd)? {
+                None => {}
+                Some(Command::DirRoot) => stack.truncate(1),
+                Some(Command::DirPush(dir)) => {
+                    let dir = stack.last()
+                        .unwrap()
+                        .borrow_mut()
+                        .get_or_insert_dir(dir);
+                    stack.push(dir);
+                }
+                Some(Command::DirPop) if stack.len() == 1 => {
+                    diag.report::<()>(Err(Error::parse(line, chunk.trim(), "'cd <dir>' or 'cd /', the root directory has no parent")))?;
+                }
+                Some(Command::DirPop) => { stack.pop(); }
+                Some(Command::List(dirs, files)) => {
+                    let mut curr = stack.last().unwrap().borrow_mut();
+                    curr.set_dirs(dirs);
+                    curr.set_files(files);
+                }
+            }
+            line += chunk.matches('\n').count();
+        }
+
+        Ok(fs)
+    }
+
+    fn part_one(fs: &DirectoryRef) -> Result<Answer, Error> {
+        let fs = fs.as_ref().borrow();
+        let answer: u32 = fs.dirs()
+            .into_iter()
+            .filter_map(|dir| {
+                let size = dir.as_ref().borrow().size();
+                if size < 100000 { Some(size) } else { None }
+            })
+            .sum();
+
+        Ok(answer.into())
+    }
+
+    fn part_two(fs: &DirectoryRef) -> Result<Answer, Error> {
+        let fs = fs.as_ref().borrow();
+        let space_needed = 70000000u32.checked_sub(fs.size())
+            .and_then(|free| 30000000u32.checked_sub(free))
+            .ok_or(Error::solve("Oops, the file system doesn't fit the disk or already has enough free space"))?;
+
+        let answer = fs.dirs()
+            .into_iter()
+            .filter_map(|dir| {
+                let size = dir.as_ref().borrow().size();
+                if size >= space_needed { Some(size) } else { None }
+            })
+            .min()
+            .ok_or(Error::solve("Oops, no directory is big enough"))?;
+
+        Ok(answer.into())
+    }
+}
@@ -0,0 +1,145 @@
+use crate::aoc;
+use crate::error::{Diagnostics, Error};
+use crate::solver::{Answer, Solver};
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum Hand {
+    Rock,
+    Paper,
+    Scissors,
+}
+#[derive(Copy, Clone)]
+pub enum GameResult {
+    Win,
+    Lose,
+    Draw
+}
+
+impl GameResult {
+    pub fn from(v: char) -> Option<GameResult> {
+        match v.to_ascii_uppercase() {
+            'X' => Some(GameResult::Lose),
+            'Y' => Some(GameResult::Draw),
+            'Z' => Some(GameResult::Win),
+            _ => None
+        }
+    }
+
+    pub fn points(self) -> u32 {
+        match self {
+            GameResult::Win => 6,
+            GameResult::Draw => 3,
+            GameResult::Lose => 0
+        }
+    }
+}
+
+impl Hand {
+    pub fn from(v: char) -> Option<Hand> {
+        match v.to_ascii_uppercase() {
+            'A' => Some(Hand::Rock),
+            'X' => Some(Hand::Rock),
+            'B' => Some(Hand::Paper),
+            'Y' => Some(Hand::Paper),
+            'C' => Some(Hand::Scissors),
+            'Z' => Some(Hand::Scissors),
+            _ => None
+        }
+    }
+
+    pub fn defeated_by(self) -> Hand {
+        match self {
+            Hand::Rock => Hand::Paper,
+            Hand::Paper => Hand::Scissors,
+            Hand::Scissors => Hand::Rock
+        }
+    }
+
+    pub fn wins_from(self) -> Hand {
+        match self {
+            Hand::Rock => Hand::Scissors,
+            Hand::Paper => Hand::Rock,
+            Hand::Scissors => Hand::Paper
+        }
+    }
+
+    pub fn points(self) -> u32 {
+        match self {
+            Hand::Rock => 1,
+            Hand::Paper => 2,
+            Hand::Scissors => 3,
+        }
+    }
+
+    pub fn play(self, other: Self) -> GameResult {
+        // Note to self: https://doc.rust-lang.org/rust-by-example/flow_control/match/guard.html
+        match self {
+            _ if self == other => GameResult::Draw,
+            _ if self.defeated_by() == other => GameResult::Lose,
+            _ => GameResult::Win,
+        }
+    }
+}
+
+// Opponent's hand and the second column read as both my hand and the desired game result
+pub type Round = (Hand, Hand, GameResult);
+
+fn parse_round(line: &str) -> Option<Round> {
+    let (opponent, myself) = line.trim().split_once(' ')?;
+    let [opponent] = opponent.chars().collect::<Vec<_>>()[..] else {
+        return None;
+    };
+    let [myself] = myself.chars().collect::<Vec<_>>()[..] else {
+        return None;
+    };
+
+    let opponent = Hand::from(opponent).filter(|_| "ABC".contains(opponent.to_ascii_uppercase()))?;
+    let game_result = GameResult::from(myself)?;
+    Some((opponent, Hand::from(myself)?, game_result))
+}
+
+#[aoc(day = 2, title = "Rock Paper Scissors")]
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Vec<Round>;
+
+    fn parse(input: &str, diag: &mut Diagnostics) -> Result<Vec<Round>, Error> {
+        input.lines()
+            .enumerate()
+            .map(|(i, line)| parse_round(line).ok_or(Error::parse(i + 1, line, "'<A|B|C> <X|Y|Z>'")))
+            .filter_map(|round| diag.report(round).transpose())
+            .collect()
+    }
+
+    fn part_one(rounds: &Vec<Round>) -> Result<Answer, Error> {
+        let score: u32 = rounds.iter()
+            .map(|(opponent, myself, _)| play(*opponent, *myself))
+            .sum();
+
+        Ok(score.into())
+    }
+
+    fn part_two(rounds: &Vec<Round>) -> Result<Answer, Error> {
+        let score: u32 = rounds.iter()
+            .map(|(opponent, _, game_result)| play2(*opponent, *game_result))
+            .sum();
+
+        Ok(score.into())
+    }
+}
+
+pub fn play(opponent: Hand, myself: Hand) -> u32 {
+    let game_result = myself.play(opponent);
+    myself.points() + game_result.points()
+}
+
+pub fn play2(opponent: Hand, game_result: GameResult) -> u32 {
+    let myself = match game_result {
+        GameResult::Draw => opponent,
+        GameResult::Win => opponent.defeated_by(),
+        GameResult::Lose => opponent.wins_from()
+    };
+
+    myself.points() + game_result.points()
+}
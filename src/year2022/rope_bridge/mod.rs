@@ -0,0 +1,93 @@
+use crate::aoc;
+use crate::error::{Diagnostics, Error};
+use crate::solver::{Answer, Part, Solver};
+use crate::utils::{Grid, Point};
+use std::collections::HashSet;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Motion {
+    direction: Point,
+    steps: u32,
+}
+
+fn parse_motion(i: usize, line: &str) -> Result<Motion, Error> {
+    let expected = "'<U|D|L|R> <steps>', e.g. 'R 4'";
+    let (direction, steps) = line.split_once(' ')
+        .ok_or(Error::parse(i + 1, line, expected))?;
+    let direction = match direction {
+        "U" => Point::UP,
+        "D" => Point::DOWN,
+        "L" => Point::LEFT,
+        "R" => Point::RIGHT,
+        _ => return Err(Error::parse(i + 1, line, expected).at_column(1)),
+    };
+    let steps = steps.parse()
+        .map_err(|_| Error::parse(i + 1, line, expected).at_column(3))?;
+    Ok(Motion { direction, steps })
+}
+
+// Moves the head one step at a time, every other knot follows the knot before it whenever they
+// stop touching. Returns the positions the tail visited, the rope starts with all knots at the origin.
+pub fn simulate(motions: &[Motion], knots: usize) -> HashSet<Point> {
+    let mut rope = vec![Point::default(); knots.max(1)];
+    let mut visited = HashSet::from([Point::default()]);
+    for motion in motions {
+        for _ in 0 .. motion.steps {
+            rope[0] += motion.direction;
+            for i in 1 .. rope.len() {
+                if rope[i].chebyshev(rope[i - 1]) <= 1 {
+                    break;
+                }
+                let step = (rope[i - 1] - rope[i]).signum();
+                rope[i] += step;
+            }
+            visited.insert(rope[rope.len() - 1]);
+        }
+    }
+    visited
+}
+
+fn knots(part: Part) -> usize {
+    if part == Part::One { 2 } else { 10 }
+}
+
+// '#' for the visited positions and 's' for the start, cropped to the visited positions which include the start
+fn render_visited(visited: &HashSet<Point>) -> String {
+    let x = visited.iter().map(|x| x.x);
+    let y = visited.iter().map(|x| x.y);
+    let min = Point::new(x.clone().min().unwrap_or(0), y.clone().min().unwrap_or(0));
+    let max = Point::new(x.max().unwrap_or(0), y.max().unwrap_or(0));
+    let size = max - min;
+    let mut grid = Grid::filled(size.x as usize + 1, size.y as usize + 1, '.');
+    for pos in visited {
+        let offset = *pos - min;
+        grid[(offset.x as usize, offset.y as usize)] = if *pos == Point::default() { 's' } else { '#' };
+    }
+    grid.to_string()
+}
+
+#[aoc(day = 9, title = "Rope Bridge")]
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Vec<Motion>;
+
+    fn parse(input: &str, diag: &mut Diagnostics) -> Result<Vec<Motion>, Error> {
+        input.lines()
+            .enumerate()
+            .filter_map(|(i, line)| diag.report(parse_motion(i, line)).transpose())
+            .collect()
+    }
+
+    fn part_one(motions: &Vec<Motion>) -> Result<Answer, Error> {
+        Ok(simulate(motions, knots(Part::One)).len().into())
+    }
+
+    fn part_two(motions: &Vec<Motion>) -> Result<Answer, Error> {
+        Ok(simulate(motions, knots(Part::Two)).len().into())
+    }
+
+    fn render(motions: &Vec<Motion>, part: Part) -> Result<Option<String>, Error> {
+        Ok(Some(render_visited(&simulate(motions, knots(part)))))
+    }
+}
@@ -0,0 +1,122 @@
+use crate::aoc;
+use crate::error::{Diagnostics, Error};
+use crate::solver::{Answer, Solver};
+use std::collections::HashSet;
+
+pub fn shared_char(mut vec: Vec<String>) -> Option<char> {
+    let mut x: Vec<HashSet<char>> = Vec::new();
+    let y = vec.pop()?;
+
+    vec.into_iter().for_each(|s| x.push(s.chars().collect()));
+    y.chars().find(|c| x.iter().all(|x| x.contains(c)))
+}
+
+pub fn priority(c: char) -> Option<u32> {
+    match c {
+        'a'..='z' => Some(c as u32 - 0x60),
+        'A'..='Z' => Some(c as u32 - 0x26),
+        _ => None
+    }
+}
+
+fn parse_rucksack(i: usize, line: &str) -> Result<String, Error> {
+    if let Some(column) = line.chars().position(|c| priority(c).is_none()) {
+        return Err(Error::parse(i + 1, line, "items a-z or A-Z").at_column(column + 1));
+    }
+    if line.is_empty() || !line.len().is_multiple_of(2) {
+        return Err(Error::parse(i + 1, line, "an even, non-zero number of items"));
+    }
+
+    let (x, y) = line.split_at(line.len() / 2);
+    if shared_char(vec![String::from(x), String::from(y)]).is_none() {
+        return Err(Error::parse(i + 1, line, "an item type in both compartments"));
+    }
+    Ok(line.into())
+}
+
+pub struct Chunked<I>
+where
+    I: Iterator
+{
+    it: I
+}
+
+impl<I> Iterator for Chunked<I>
+where
+    I: Iterator
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Vec<I::Item>> {
+        let mut chunk = Vec::new();
+        for i in 0..3 {
+            match self.it.next() {
+                None if i == 0 => return None,
+                None => return Some(chunk),
+                Some(item) => chunk.push(item),
+            }
+        }
+        Some(chunk)
+    }
+}
+
+pub trait ChunkedIterator<I>
+where
+    I: Iterator
+{
+    fn chunked(self) -> Chunked<I>;
+}
+
+impl<I> ChunkedIterator<I> for I
+where
+    I: Iterator
+{
+    fn chunked(self) -> Chunked<I> {
+        Chunked { it: self }
+    }
+}
+
+#[aoc(day = 3, title = "Rucksack Reorganization")]
+pub struct Solution;
+
+impl Solver for Solution {
+    // Every rucksack with its line number, which still counts the lines skipped by lenient parsing
+    type Input = Vec<(usize, String)>;
+
+    fn parse(input: &str, diag: &mut Diagnostics) -> Result<Self::Input, Error> {
+        input.lines()
+            .enumerate()
+            .filter_map(|(i, line)| diag.report(parse_rucksack(i, line).map(|x| (i + 1, x))).transpose())
+            .collect()
+    }
+
+    fn part_one(lines: &Self::Input) -> Result<Answer, Error> {
+        let mut sum = 0;
+        for (n, line) in lines {
+            let (x, y) = line.split_at(line.len() / 2);
+            sum += shared_char(vec![String::from(x), String::from(y)])
+                .and_then(priority)
+                .ok_or(Error::parse(*n, line, "an item type in both compartments"))?;
+        }
+
+        Ok(sum.into())
+    }
+
+    // Groups are every three lines of the input, a group with a skipped rucksack can't be solved
+    fn part_two(lines: &Self::Input) -> Result<Answer, Error> {
+        let mut sum = 0;
+        for group in lines.iter().cloned().chunked() {
+            let first = group[0].0;
+            let text = group.iter().map(|(_, x)| x.as_str()).collect::<Vec<_>>().join(" ");
+            let complete = group.len() == 3 && (first - 1) % 3 == 0 && group.iter().zip(first ..).all(|((n, _), x)| *n == x);
+            if !complete {
+                return Err(Error::parse(first, &text, "a group of three rucksacks, none of them skipped"));
+            }
+            sum += shared_char(group.into_iter().map(|(_, x)| x).collect())
+                .and_then(priority)
+                .ok_or(Error::parse(first, &text, "an item type shared by the group"))?;
+        }
+
+        Ok(sum.into())
+    }
+}
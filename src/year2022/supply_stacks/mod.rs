@@ -0,0 +1,163 @@
+use crate::aoc;
+use crate::error::{Diagnostics, Error};
+use crate::solver::{Answer, Solver};
+
+pub type Crate = char;
+pub type CrateStack = Vec<Crate>;
+
+#[derive(Debug, Default, Clone)]
+pub struct Crane {
+    stacks: [CrateStack; 9]
+}
+
+impl Crane {
+    pub fn parse(str: &str, diag: &mut Diagnostics) -> Result<Crane, Error> {
+        let mut crane = Crane::default();
+        let mut stacks: Vec<&str> = str.split("\n").collect();
+
+        match stacks.pop() {
+            Some(numbers) if !numbers.trim().is_empty() => {
+                for (n, line) in stacks.into_iter().enumerate().rev() {
+                    let mut it = line.chars();
+                    it.next(); // Skips first character '['
+                    for (i, value) in it.step_by(4).enumerate() {
+                        if value == ' ' {
+                            continue;
+                        }
+                        if i >= crane.stacks.len() {
+                            diag.report::<()>(Err(Error::parse(n + 1, line, "at most 9 stacks").at_column(i * 4 + 2)))?;
+                            continue;
+                        }
+                        crane.stacks[i].push(value)
+                    }
+                }
+                Ok(crane)
+            }
+            _ => Err(Error::parse(1, str, "a drawing of the crate stacks followed by the stack numbers"))
+        }
+    }
+}
+
+pub trait CrateMover9000
+{
+    fn apply_instr(&mut self, instr: &CraneInstruction) -> Result<(), Error>;
+}
+
+pub trait CrateMover9001
+{
+    fn apply_instr(&mut self, instr: &CraneInstruction) -> Result<(), Error>;
+}
+
+impl Crane {
+    fn check_instr(&self, instr: &CraneInstruction) -> Result<(), Error> {
+        if self.stacks[instr.from].len() < instr.num_crates {
+            return Err(Error::solve(&format!("Oops, can't move {} crates from stack {} holding {} crates",
+                instr.num_crates, instr.from + 1, self.stacks[instr.from].len())));
+        }
+        Ok(())
+    }
+}
+
+impl CrateMover9000 for Crane {
+    fn apply_instr(&mut self, instr: &CraneInstruction) -> Result<(), Error> {
+        self.check_instr(instr)?;
+
+        if instr.from != instr.to {
+            for _ in 0 .. instr.num_crates {
+                let x = self.stacks[instr.from].pop().unwrap();
+                self.stacks[instr.to].push(x);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl CrateMover9001 for Crane {
+    fn apply_instr(&mut self, instr: &CraneInstruction) -> Result<(), Error> {
+        self.check_instr(instr)?;
+
+        if instr.from != instr.to {
+            let at = self.stacks[instr.from].len() - instr.num_crates;
+            let mut x = self.stacks[instr.from].split_off(at);
+            self.stacks[instr.to].append(&mut x);
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct CraneInstruction {
+    num_crates: usize,
+    from: usize,
+    to: usize,
+}
+
+impl CraneInstruction {
+    // 'line' is the line number of the instruction, used for reporting errors
+    pub fn parse(line: usize, str: &str) -> Result<CraneInstruction, Error> {
+        let error = || Error::parse(line, str, "'move <crates> from <1-9> to <1-9>'");
+        let stack = |x: &str| x.parse::<usize>()
+            .ok()
+            .filter(|x| (1 ..= 9).contains(x))
+            .map(|x| x - 1);
+
+        let split: Vec<&str> = str.split(' ').collect();
+        match split[..] {
+            ["move", num, "from", from, "to", to] => {
+                let instr = CraneInstruction {
+                    num_crates: num.parse().map_err(|_| error())?,
+                    from: stack(from).ok_or_else(error)?,
+                    to: stack(to).ok_or_else(error)?,
+                };
+                Ok(instr)
+            }
+            _ => Err(error())
+        }
+    }
+}
+
+pub fn top_crates(crane: &Crane) -> String {
+    crane.stacks.iter()
+        .filter_map(|x| x.last().cloned())
+        .collect()
+}
+
+#[aoc(day = 5, title = "Supply Stacks")]
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = (Crane, Vec<CraneInstruction>);
+
+    fn parse(input: &str, diag: &mut Diagnostics) -> Result<Self::Input, Error> {
+        let (header, data) = input.split_once("\n\n")
+            .ok_or(Error::parse(1, input.lines().next().unwrap_or_default(),
+                "a drawing of the crate stacks, an empty line and the instructions"))?;
+
+        let crane = Crane::parse(header, diag)?;
+        let offset = header.lines().count() + 2;
+        let instrs = data.split('\n')
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .filter_map(|(i, line)| diag.report(CraneInstruction::parse(offset + i, line)).transpose())
+            .collect::<Result<_, _>>()?;
+        Ok((crane, instrs))
+    }
+
+    fn part_one((crane, instrs): &Self::Input) -> Result<Answer, Error> {
+        let mut crane = crane.clone();
+        for instr in instrs {
+            CrateMover9000::apply_instr(&mut crane, instr)?;
+        }
+
+        Ok(top_crates(&crane).into())
+    }
+
+    fn part_two((crane, instrs): &Self::Input) -> Result<Answer, Error> {
+        let mut crane = crane.clone();
+        for instr in instrs {
+            CrateMover9001::apply_instr(&mut crane, instr)?;
+        }
+
+        Ok(top_crates(&crane).into())
+    }
+}
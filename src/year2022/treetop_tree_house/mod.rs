@@ -0,0 +1,67 @@
+use crate::aoc;
+use crate::error::{Diagnostics, Error};
+use crate::solver::{Answer, Solver};
+use crate::utils::Grid;
+
+// Every row and column of the grid, in both directions, as the positions along it
+fn sight_lines(grid: &Grid<u8>) -> Vec<Vec<(usize, usize)>> {
+    let rows = (0 .. grid.height()).map(|y| (0 .. grid.width()).map(|x| (x, y)).collect::<Vec<_>>());
+    let columns = (0 .. grid.width()).map(|x| (0 .. grid.height()).map(|y| (x, y)).collect::<Vec<_>>());
+    rows.chain(columns)
+        .flat_map(|line| {
+            let reversed = line.iter().rev().copied().collect();
+            [line, reversed]
+        })
+        .collect()
+}
+
+// For every tree along a line, the index of the closest tree before it that is at least as tall.
+// The stack holds the trees that can still block the view, from tall to short, so each tree is
+// pushed and popped once and a line takes linear time.
+fn blocking_trees(grid: &Grid<u8>, line: &[(usize, usize)]) -> Vec<Option<usize>> {
+    let mut stack: Vec<usize> = Vec::new();
+    line.iter()
+        .enumerate()
+        .map(|(i, pos)| {
+            while stack.last().is_some_and(|x| grid[line[*x]] < grid[*pos]) {
+                stack.pop();
+            }
+            let blocking = stack.last().copied();
+            stack.push(i);
+            blocking
+        })
+        .collect()
+}
+
+#[aoc(day = 8, title = "Treetop Tree House")]
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Grid<u8>;
+
+    fn parse(input: &str, _diag: &mut Diagnostics) -> Result<Grid<u8>, Error> {
+        Grid::parse(input, "tree heights from 0 to 9", |c| c.to_digit(10).map(|x| x as u8))
+    }
+
+    // A tree is visible from the edge a line starts at if no tree before it blocks the view
+    fn part_one(grid: &Grid<u8>) -> Result<Answer, Error> {
+        let mut visible = grid.map(|_| false);
+        for line in sight_lines(grid) {
+            for (pos, blocking) in line.iter().zip(blocking_trees(grid, &line)) {
+                visible[*pos] |= blocking.is_none();
+            }
+        }
+        Ok(visible.positions().filter(|x| visible[*x]).count().into())
+    }
+
+    // The viewing distance looking back along a line ends at the blocking tree, or at the edge
+    fn part_two(grid: &Grid<u8>) -> Result<Answer, Error> {
+        let mut scores = grid.map(|_| 1);
+        for line in sight_lines(grid) {
+            for (i, blocking) in blocking_trees(grid, &line).into_iter().enumerate() {
+                scores[line[i]] *= i - blocking.unwrap_or(0);
+            }
+        }
+        Ok(scores.positions().map(|x| scores[x]).max().unwrap_or(0).into())
+    }
+}
@@ -0,0 +1,43 @@
+use crate::aoc;
+use crate::error::{Diagnostics, Error};
+use crate::solver::{Answer, Solver};
+use std::collections::HashSet;
+
+pub fn start_marker(line: &str, len: usize) -> Option<usize> {
+    let line = line.chars().collect::<Vec<_>>();
+    let mut window = line.windows(len);
+    window.position(|window| {
+        let mut unique = HashSet::new();
+        window.iter().all(move |x| unique.insert(x))
+    })
+    .map(|pos| pos + len)
+}
+
+fn sum_of_markers(lines: &[String], len: usize) -> Result<usize, Error> {
+    let mut sum = 0;
+    for (i, line) in lines.iter().enumerate() {
+        sum += start_marker(line, len)
+            .ok_or(Error::parse(i + 1, line, &format!("a marker of {} distinct characters", len)))?;
+    }
+    Ok(sum)
+}
+
+#[aoc(day = 6, title = "Tuning Trouble")]
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Vec<String>;
+
+    // A missing marker is reported by the part that looks for it, see 'sum_of_markers'
+    fn parse(input: &str, _diag: &mut Diagnostics) -> Result<Vec<String>, Error> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part_one(lines: &Vec<String>) -> Result<Answer, Error> {
+        Ok(sum_of_markers(lines, 4)?.into())
+    }
+
+    fn part_two(lines: &Vec<String>) -> Result<Answer, Error> {
+        Ok(sum_of_markers(lines, 14)?.into())
+    }
+}